@@ -0,0 +1,30 @@
+//! Merkle allowlists for `claim_with_proof`, in the encoding of OpenZeppelin's
+//! `StandardMerkleTree` with leaf types `["uint256", "address"]`. The
+//! `poap-allowlist` crate builds the trees and proofs off-chain.
+use alloy_sol_types::SolValue;
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U256},
+    crypto::keccak,
+};
+
+/// Returns the leaf for `account` attending `event_id`,
+/// `keccak256(keccak256(abi.encode(eventId, account)))`.
+pub fn leaf(event_id: U256, account: Address) -> B256 {
+    keccak(keccak((event_id, account).abi_encode()))
+}
+
+/// Returns whether `proof` proves that `leaf` is part of the tree with
+/// `root`. Pairs are hashed in ascending order, so proofs carry no
+/// left/right flags.
+pub fn verify(proof: &[B256], root: B256, leaf: B256) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, &sibling| hash_pair(node, sibling))
+        == root
+}
+
+/// Hashes two nodes in ascending order.
+pub fn hash_pair(a: B256, b: B256) -> B256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    keccak([left.as_slice(), right.as_slice()].concat())
+}
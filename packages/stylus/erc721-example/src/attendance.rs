@@ -0,0 +1,52 @@
+//! Attendance queries for other contracts, e.g. DAOs, discount systems and
+//! token-gated mints.
+use alloc::vec::Vec;
+
+use stylus_sdk::{
+    alloy_primitives::{aliases::B32, Address, U256},
+    function_selector,
+};
+
+/// Read-only view of who attended which `Poap` event.
+///
+/// Solidity interface:
+///
+/// ```solidity
+/// interface IPoapAttendance {
+///     function hasAttended(uint256 eventId, address account) external view returns (bool);
+///     function hasAttendedAny(uint256[] calldata eventIds, address account) external view returns (bool);
+///     function attendedCount(address account) external view returns (uint256);
+/// }
+/// ```
+///
+/// Contracts detect support through ERC-165 with the id `0x66eb26eb`,
+/// [`IPoapAttendance::interface_id`]. A revoked badge no longer counts as
+/// attendance.
+pub trait IPoapAttendance {
+    /// Returns whether `account` holds the badge of `event_id`.
+    fn has_attended(&self, event_id: U256, account: Address) -> bool;
+
+    /// Returns whether `account` holds the badge of at least one of
+    /// `event_ids`.
+    fn has_attended_any(&self, event_ids: Vec<U256>, account: Address) -> bool;
+
+    /// Returns the number of events `account` holds a badge of. Achievement
+    /// badges are not counted.
+    fn attended_count(&self, account: Address) -> U256;
+
+    /// Returns the ERC-165 id of the interface, the XOR of its selectors.
+    fn interface_id() -> B32
+    where
+        Self: Sized,
+    {
+        let selectors = [
+            function_selector!("hasAttended", U256, Address),
+            function_selector!("hasAttendedAny", Vec<U256>, Address),
+            function_selector!("attendedCount", Address),
+        ];
+        let id = selectors.iter().fold([0u8; 4], |id, selector| {
+            core::array::from_fn(|i| id[i] ^ selector[i])
+        });
+        B32::from(id)
+    }
+}
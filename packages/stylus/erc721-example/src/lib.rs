@@ -7,31 +7,214 @@ use openzeppelin_stylus::{
     access::ownable::{self, Ownable, OwnableInvalidOwner, OwnableUnauthorizedAccount},
     token::erc721::{
         self,
-        extensions::{Erc721Metadata, IErc721Metadata},
-        Erc721, IErc721,
+        extensions::{
+            enumerable::{self, ERC721EnumerableForbiddenBatchMint, ERC721OutOfBoundsIndex},
+            Erc721Enumerable, Erc721Metadata, IErc721Enumerable, IErc721Metadata,
+        },
+        ERC721IncorrectOwner, ERC721InsufficientApproval, ERC721InvalidApprover,
+        ERC721InvalidOperator, ERC721InvalidOwner, ERC721InvalidReceiver, ERC721InvalidSender,
+        ERC721NonexistentToken, Erc721, IErc721, InvalidReceiverWithReason,
     },
     utils::introspection::erc165::IErc165,
 };
 
-use alloy_sol_types::sol;
+use alloy_sol_types::{sol, SolCall, SolValue};
 use stylus_sdk::{
     abi::Bytes,
-    alloy_primitives::{aliases::B32, Address, U256},
+    alloy_primitives::{aliases::B32, b256, fixed_bytes, Address, B256, U256},
+    call::Call,
     prelude::*,
-    storage::{StorageAddress, StorageBool, StorageMap, StorageString, StorageU256},
+    storage::{
+        StorageAddress, StorageB256, StorageBool, StorageMap, StorageString, StorageU256,
+        StorageVec,
+    },
+    stylus_core::log,
 };
 
+mod allowlist;
+mod attendance;
+mod metadata;
+mod pagination;
+#[path = "../../vendor/openzeppelin-stylus/src/utils/storage_slot.rs"]
+mod storage_slot;
+
+pub use attendance::IPoapAttendance;
+
+use metadata::Metadata;
+use pagination::page_bounds;
+use storage_slot::StorageSlot;
+
+/// ERC-1967 implementation slot,
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`.
+const IMPLEMENTATION_SLOT: B256 =
+    b256!("0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// What a receiver returns to accept a safe mint, the selector of
+/// `onERC721Received`.
+const ERC721_RECEIVED: B32 = fixed_bytes!("0x150b7a02");
+
+/// High bit of `token_event` marking a token as an achievement badge. The
+/// remaining bits hold the achievement id instead of an event id.
+const ACHIEVEMENT_NAMESPACE: U256 = U256::from_limbs([0, 0, 0, 1 << 63]);
+
+/// Gas forwarded to an event's mint hook. Bounds what a misbehaving hook can
+/// cost the minter.
+const MINT_HOOK_GAS_STIPEND: u64 = 100_000;
+
+/// Role every event has as role 0. It is uncapped and minted by the event's
+/// minters.
+const DEFAULT_ROLE_NAME: &str = "Attendee";
+
+/// ABI tuple returned by the event catalog views:
+/// `(event_id, name, organizer, active, attendee_count)`.
+pub type EventView = (U256, String, Address, bool, U256);
+
+/// ABI tuple returned by the holder profile view:
+/// `(token_id, event_id, event_name)`.
+pub type HolderBadgeView = (U256, U256, String);
+
+/// ABI tuple returned by the token check-in view:
+/// `(event_id, minted_at, block_number, minter)`.
+pub type TokenInfoView = (U256, U256, U256, Address);
+
+/// ABI tuple returned by the event role view:
+/// `(role_id, name, supply_cap, supply)`. A `supply_cap` of 0 is uncapped.
+pub type EventRoleView = (U256, String, U256, U256);
+
 sol! {
     error UnauthorizedAction();
-    error InvalidOperation();
+    error EventNotFound(uint256 eventId);
+    error EventInactive(uint256 eventId);
+    error AlreadyAttended(uint256 eventId, address attendee);
+    error NotEventMinter(uint256 eventId, address account);
+    error AchievementNotFound(uint256 achievementId);
+    error AchievementInactive(uint256 achievementId);
+    error AchievementAlreadyClaimed(uint256 achievementId, address account);
+    error AchievementNotEarned(
+        uint256 achievementId,
+        address account,
+        uint256 attended,
+        uint256 threshold
+    );
+    error InvalidAchievementThreshold(uint256 threshold, uint256 eventCount);
+    error DuplicateAchievementEvent(uint256 eventId);
+    error AlreadyInitialized();
+    error ERC1967InvalidImplementation(address implementation);
+    error UUPSUnsupportedProxiableUUID(bytes32 slot);
+    error UUPSUnauthorizedCallContext();
+    error FailedCall();
+    error UntrustedForwarder(address forwarder);
+    error UnsupportedForwardedCall(bytes4 selector);
+    error InvalidForwardedCall();
+    error NotEventOrganizer(uint256 eventId, address account);
+    error MintHookReverted(uint256 eventId, address hook);
+    error RoleNotFound(uint256 eventId, uint256 roleId);
+    error NotRoleMinter(uint256 eventId, uint256 roleId, address account);
+    error RoleSupplyExceeded(uint256 eventId, uint256 roleId, uint256 supplyCap);
+    error InvalidSessionThreshold(uint256 threshold);
+    error NotSessionEvent(uint256 eventId);
+    error SessionNotFound(uint256 eventId, uint256 sessionId);
+    error AlreadyCheckedIn(uint256 eventId, uint256 sessionId, address attendee);
+    error SessionThresholdNotMet(
+        uint256 eventId,
+        address attendee,
+        uint256 attended,
+        uint256 threshold
+    );
+    error InvalidAllowlistProof(uint256 eventId, address account);
+
+    event EventCreated(uint256 indexed eventId, address indexed organizer, string name);
+    event EventMinterAdded(uint256 indexed eventId, address indexed minter);
+    event BadgeMinted(uint256 indexed eventId, uint256 indexed tokenId, address indexed attendee);
+    event Upgraded(address indexed implementation);
+    event TrustedForwarderSet(address indexed forwarder);
+    event EventMintHookSet(uint256 indexed eventId, address indexed hook, bool revertOnFailure);
+    event MintHookFailed(uint256 indexed eventId, uint256 indexed tokenId, address indexed hook);
+    event EventRoleAdded(uint256 indexed eventId, uint256 indexed roleId, string name, uint256 supplyCap);
+    event RoleMinterSet(uint256 indexed eventId, uint256 indexed roleId, address indexed minter, bool allowed);
+    event SessionAdded(uint256 indexed eventId, uint256 indexed sessionId, string name);
+    event SessionCheckedIn(uint256 indexed eventId, uint256 indexed sessionId, address indexed attendee);
+    event AchievementCreated(uint256 indexed achievementId, string name, uint256 threshold);
+    event EventAllowlistRootSet(uint256 indexed eventId, bytes32 root);
+}
+
+// Calls the trusted forwarder may relay through `executeForwarded`.
+sol! {
+    function mintToken(uint256 eventId, address to);
+    function mintTokenSafe(uint256 eventId, address to, bytes data);
+    function mintTokenWithRole(uint256 eventId, address to, uint256 roleId);
+    function revokeBadge(uint256 tokenId);
+    function claimAchievement(uint256 achievementId);
+    function createEvent(string name, address organizer);
+    function addEventMinter(uint256 eventId, address minter);
+    function addEventRole(uint256 eventId, string name, uint256 supplyCap);
+    function setRoleMinter(uint256 eventId, uint256 roleId, address minter, bool allowed);
+    function createSessionEvent(string name, address organizer, uint256 sessionThreshold, bool autoMint);
+    function addEventSession(uint256 eventId, string name);
+    function checkIn(uint256 eventId, uint256 sessionId, address attendee);
+    function claimEventBadge(uint256 eventId);
+    function claimWithProof(uint256 eventId, bytes32[] proof);
+}
+
+sol_interface! {
+    interface IErc1822Proxiable {
+        function proxiableUUID() external view returns (bytes32);
+    }
+
+    interface IPoapMintHook {
+        function onPoapMinted(uint256 event_id, uint256 token_id, address recipient) external;
+    }
+
+    interface IErc721Receiver {
+        function onERC721Received(address operator, address from, uint256 token_id, bytes data) external returns (bytes4);
+    }
 }
 
 #[derive(SolidityError)]
 pub enum PoapError {
     UnauthorizedAction(UnauthorizedAction),
-    InvalidOperation(InvalidOperation),
+    EventNotFound(EventNotFound),
+    EventInactive(EventInactive),
+    AlreadyAttended(AlreadyAttended),
+    NotEventMinter(NotEventMinter),
+    AchievementNotFound(AchievementNotFound),
+    AchievementInactive(AchievementInactive),
+    AchievementAlreadyClaimed(AchievementAlreadyClaimed),
+    AchievementNotEarned(AchievementNotEarned),
+    InvalidAchievementThreshold(InvalidAchievementThreshold),
+    DuplicateAchievementEvent(DuplicateAchievementEvent),
+    AlreadyInitialized(AlreadyInitialized),
+    ERC1967InvalidImplementation(ERC1967InvalidImplementation),
+    UUPSUnsupportedProxiableUUID(UUPSUnsupportedProxiableUUID),
+    UUPSUnauthorizedCallContext(UUPSUnauthorizedCallContext),
+    FailedCall(FailedCall),
+    UntrustedForwarder(UntrustedForwarder),
+    UnsupportedForwardedCall(UnsupportedForwardedCall),
+    InvalidForwardedCall(InvalidForwardedCall),
+    NotEventOrganizer(NotEventOrganizer),
+    MintHookReverted(MintHookReverted),
+    RoleNotFound(RoleNotFound),
+    NotRoleMinter(NotRoleMinter),
+    RoleSupplyExceeded(RoleSupplyExceeded),
+    InvalidSessionThreshold(InvalidSessionThreshold),
+    NotSessionEvent(NotSessionEvent),
+    SessionNotFound(SessionNotFound),
+    AlreadyCheckedIn(AlreadyCheckedIn),
+    SessionThresholdNotMet(SessionThresholdNotMet),
+    InvalidAllowlistProof(InvalidAllowlistProof),
     OwnableUnauthorizedAccount(OwnableUnauthorizedAccount),
     OwnableInvalidOwner(OwnableInvalidOwner),
+    ERC721InvalidOwner(ERC721InvalidOwner),
+    ERC721NonexistentToken(ERC721NonexistentToken),
+    ERC721IncorrectOwner(ERC721IncorrectOwner),
+    ERC721InvalidSender(ERC721InvalidSender),
+    ERC721InvalidReceiver(ERC721InvalidReceiver),
+    InvalidReceiverWithReason(InvalidReceiverWithReason),
+    ERC721InsufficientApproval(ERC721InsufficientApproval),
+    ERC721InvalidApprover(ERC721InvalidApprover),
+    ERC721InvalidOperator(ERC721InvalidOperator),
+    ERC721OutOfBoundsIndex(ERC721OutOfBoundsIndex),
+    ERC721EnumerableForbiddenBatchMint(ERC721EnumerableForbiddenBatchMint),
 }
 
 impl From<ownable::Error> for PoapError {
@@ -43,11 +226,41 @@ impl From<ownable::Error> for PoapError {
     }
 }
 
+impl From<erc721::Error> for PoapError {
+    fn from(error: erc721::Error) -> Self {
+        match error {
+            erc721::Error::InvalidOwner(err) => PoapError::ERC721InvalidOwner(err),
+            erc721::Error::NonexistentToken(err) => PoapError::ERC721NonexistentToken(err),
+            erc721::Error::IncorrectOwner(err) => PoapError::ERC721IncorrectOwner(err),
+            erc721::Error::InvalidSender(err) => PoapError::ERC721InvalidSender(err),
+            erc721::Error::InvalidReceiver(err) => PoapError::ERC721InvalidReceiver(err),
+            erc721::Error::InvalidReceiverWithReason(err) => {
+                PoapError::InvalidReceiverWithReason(err)
+            }
+            erc721::Error::InsufficientApproval(err) => PoapError::ERC721InsufficientApproval(err),
+            erc721::Error::InvalidApprover(err) => PoapError::ERC721InvalidApprover(err),
+            erc721::Error::InvalidOperator(err) => PoapError::ERC721InvalidOperator(err),
+        }
+    }
+}
+
+impl From<enumerable::Error> for PoapError {
+    fn from(error: enumerable::Error) -> Self {
+        match error {
+            enumerable::Error::OutOfBoundsIndex(err) => PoapError::ERC721OutOfBoundsIndex(err),
+            enumerable::Error::EnumerableForbiddenBatchMint(err) => {
+                PoapError::ERC721EnumerableForbiddenBatchMint(err)
+            }
+        }
+    }
+}
+
 #[storage]
 #[entrypoint]
 pub struct Poap {
     erc721: Erc721,
     metadata: Erc721Metadata,
+    enumerable: Erc721Enumerable,
     owner: Ownable,
 
     last_token_id: StorageU256,
@@ -59,44 +272,567 @@ pub struct Poap {
     event_active: StorageMap<U256, StorageBool>,
     event_minters: StorageMap<U256, StorageMap<Address, StorageBool>>,
     event_attendance: StorageMap<U256, StorageMap<Address, StorageBool>>,
+    event_attendees: StorageMap<U256, StorageVec<StorageAddress>>,
+    event_attendee_index: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    organizer_events: StorageMap<Address, StorageVec<StorageU256>>,
+
+    last_achievement_id: StorageU256,
+    achievement_name: StorageMap<U256, StorageString>,
+    achievement_events: StorageMap<U256, StorageVec<StorageU256>>,
+    achievement_threshold: StorageMap<U256, StorageU256>,
+    achievement_active: StorageMap<U256, StorageBool>,
+    achievement_claimed: StorageMap<U256, StorageMap<Address, StorageBool>>,
+
+    initialized: StorageBool,
+
+    trusted_forwarder: StorageAddress,
+    /// Signer of the call being relayed by `execute_forwarded`, zero otherwise.
+    /// Written twice per relayed call, see `execute_forwarded`.
+    forwarded_sender: StorageAddress,
+
+    event_mint_hook: StorageMap<U256, StorageAddress>,
+    event_mint_hook_required: StorageMap<U256, StorageBool>,
+
+    token_minted_at: StorageMap<U256, StorageU256>,
+    token_minted_block: StorageMap<U256, StorageU256>,
+    token_minter: StorageMap<U256, StorageAddress>,
+
+    token_role: StorageMap<U256, StorageU256>,
+    event_role_count: StorageMap<U256, StorageU256>,
+    event_role_name: StorageMap<U256, StorageMap<U256, StorageString>>,
+    event_role_cap: StorageMap<U256, StorageMap<U256, StorageU256>>,
+    event_role_supply: StorageMap<U256, StorageMap<U256, StorageU256>>,
+    role_minters: StorageMap<U256, StorageMap<U256, StorageMap<Address, StorageBool>>>,
+
+    /// Sessions an attendee must check in to before the event badge can be
+    /// minted. Zero for events without sessions.
+    event_session_threshold: StorageMap<U256, StorageU256>,
+    event_auto_mint: StorageMap<U256, StorageBool>,
+    event_session_count: StorageMap<U256, StorageU256>,
+    event_session_name: StorageMap<U256, StorageMap<U256, StorageString>>,
+    session_check_ins: StorageMap<U256, StorageMap<U256, StorageMap<Address, StorageBool>>>,
+    attended_sessions: StorageMap<U256, StorageMap<Address, StorageU256>>,
+
+    event_allowlist_root: StorageMap<U256, StorageB256>,
+
+    /// Revoked entries of `event_attendees`. The list is append-only, so its
+    /// live attendees are its length minus this count.
+    event_revoked_attendees: StorageMap<U256, StorageU256>,
 }
 
 impl Poap {
+    /// Returns the account the current call is made on behalf of: the signer
+    /// while the trusted forwarder relays a call, `msg_sender` otherwise.
+    /// Contracts called back during a relayed call, like mint hooks and
+    /// ERC-721 receivers, are not the forwarder and act as themselves.
+    fn msg_sender(&self) -> Address {
+        let sender = self.vm().msg_sender();
+        let forwarded = self.forwarded_sender.get();
+        if forwarded.is_zero() || !self.is_trusted_forwarder(sender) {
+            sender
+        } else {
+            forwarded
+        }
+    }
+
     fn ensure_owner(&self) -> Result<(), PoapError> {
-        self.owner.only_owner().map_err(PoapError::from)
+        let sender = self.msg_sender();
+        if sender != self.owner.owner() {
+            return Err(PoapError::OwnableUnauthorizedAccount(
+                OwnableUnauthorizedAccount { account: sender },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reverts unless the call runs in the storage of an ERC-1967 proxy. The
+    /// implementation's own slot is never set, so calls made directly on the
+    /// implementation, or through an EIP-1167 clone of it, are rejected.
+    fn only_proxy(&self) -> Result<(), PoapError> {
+        let implementation = self.get_implementation();
+        if implementation.is_zero() || implementation == self.vm().contract_address() {
+            return Err(PoapError::UUPSUnauthorizedCallContext(
+                UUPSUnauthorizedCallContext {},
+            ));
+        }
+        Ok(())
+    }
+
+    fn ensure_event_exists(&self, event_id: U256) -> Result<(), PoapError> {
+        if !self.event_exists(event_id) {
+            return Err(PoapError::EventNotFound(EventNotFound {
+                eventId: event_id,
+            }));
+        }
+        Ok(())
+    }
+
+    fn ensure_achievement_exists(&self, achievement_id: U256) -> Result<(), PoapError> {
+        if achievement_id > self.last_achievement_id.get() || achievement_id == U256::ZERO {
+            return Err(PoapError::AchievementNotFound(AchievementNotFound {
+                achievementId: achievement_id,
+            }));
+        }
+        Ok(())
+    }
+
+    fn ensure_organizer_or_owner(&self, event_id: U256) -> Result<(), PoapError> {
+        let sender = self.msg_sender();
+        if sender != self.event_organizer.get(event_id) && self.ensure_owner().is_err() {
+            return Err(PoapError::NotEventOrganizer(NotEventOrganizer {
+                eventId: event_id,
+                account: sender,
+            }));
+        }
+        Ok(())
+    }
+
+    fn ensure_role_exists(&self, event_id: U256, role_id: U256) -> Result<(), PoapError> {
+        if role_id > self.event_role_count.get(event_id) {
+            return Err(PoapError::RoleNotFound(RoleNotFound {
+                eventId: event_id,
+                roleId: role_id,
+            }));
+        }
+        Ok(())
+    }
+
+    fn ensure_session_threshold_met(
+        &self,
+        event_id: U256,
+        attendee: Address,
+    ) -> Result<(), PoapError> {
+        let threshold = self.event_session_threshold.get(event_id);
+        let attended = self.attended_sessions.get(event_id).get(attendee);
+        if attended < threshold {
+            return Err(PoapError::SessionThresholdNotMet(SessionThresholdNotMet {
+                eventId: event_id,
+                attendee,
+                attended,
+                threshold,
+            }));
+        }
+        Ok(())
+    }
+
+    fn role_name(&self, event_id: U256, role_id: U256) -> String {
+        if role_id == U256::ZERO {
+            return String::from(DEFAULT_ROLE_NAME);
+        }
+        self.event_role_name.get(event_id).get(role_id).get_string()
+    }
+
+    fn event_exists(&self, event_id: U256) -> bool {
+        event_id != U256::ZERO && event_id <= self.last_event_id.get()
+    }
+
+    fn mint_next_token(&mut self, recipient: Address) -> Result<U256, PoapError> {
+        let new_token_id = self.last_token_id.get() + U256::from(1);
+        self.last_token_id.set(new_token_id);
+
+        self.erc721._mint(recipient, new_token_id)?;
+
+        self.enumerable
+            ._add_token_to_all_tokens_enumeration(new_token_id);
+        self.enumerable
+            ._add_token_to_owner_enumeration(recipient, new_token_id, &self.erc721)?;
+
+        let minted_at = U256::from(self.vm().block_timestamp());
+        let block_number = U256::from(self.vm().block_number());
+        let minter = self.msg_sender();
+        self.token_minted_at.setter(new_token_id).set(minted_at);
+        self.token_minted_block
+            .setter(new_token_id)
+            .set(block_number);
+        self.token_minter.setter(new_token_id).set(minter);
+
+        Ok(new_token_id)
+    }
+
+    fn burn_token_internal(&mut self, token_id: U256) -> Result<Address, PoapError> {
+        let holder = self.erc721.owner_of(token_id)?;
+
+        self.erc721._burn(token_id)?;
+
+        self.enumerable
+            ._remove_token_from_owner_enumeration(holder, token_id, &self.erc721)?;
+        self.enumerable
+            ._remove_token_from_all_tokens_enumeration(token_id);
+
+        Ok(holder)
     }
 
     fn mint_badge_internal(
         &mut self,
         event_id: U256,
+        role_id: U256,
         recipient: Address,
+        data: Option<&Bytes>,
     ) -> Result<U256, PoapError> {
-        let new_token_id = self.last_token_id.get() + U256::from(1);
-        self.last_token_id.set(new_token_id);
-
-        self.erc721
-            ._mint(recipient, new_token_id)
-            .map_err(|_| PoapError::InvalidOperation(InvalidOperation {}))?;
+        let new_token_id = self.mint_next_token(recipient)?;
 
         self.token_event.setter(new_token_id).set(event_id);
+        self.token_role.setter(new_token_id).set(role_id);
+        let supply = self.event_role_supply.get(event_id).get(role_id);
+        self.event_role_supply
+            .setter(event_id)
+            .setter(role_id)
+            .set(supply + U256::from(1));
         self.event_attendance
             .setter(event_id)
             .setter(recipient)
             .set(true);
+        self.add_event_attendee(event_id, recipient);
+
+        log(
+            self.vm(),
+            BadgeMinted {
+                eventId: event_id,
+                tokenId: new_token_id,
+                attendee: recipient,
+            },
+        );
+
+        if let Some(data) = data {
+            self.check_on_erc721_received(recipient, new_token_id, data)?;
+        }
+        self.call_mint_hook(event_id, new_token_id, recipient)?;
 
         Ok(new_token_id)
     }
+
+    /// The `onERC721Received` acceptance check of `_safe_mint`. Runs once the
+    /// badge is fully recorded, so a receiver that calls back sees it.
+    fn check_on_erc721_received(
+        &mut self,
+        to: Address,
+        token_id: U256,
+        data: &Bytes,
+    ) -> Result<(), PoapError> {
+        if self.vm().code_size(to) == 0 {
+            return Ok(());
+        }
+
+        let operator = self.msg_sender();
+        let context = Call::new_mutating(self);
+        let result = IErc721Receiver::new(to).on_erc_721_received(
+            self.vm(),
+            context,
+            operator,
+            Address::ZERO,
+            token_id,
+            data.clone(),
+        );
+        match result {
+            Ok(selector) if selector == ERC721_RECEIVED => Ok(()),
+            Err(stylus_sdk::call::Error::Revert(reason)) if !reason.is_empty() => Err(
+                PoapError::InvalidReceiverWithReason(InvalidReceiverWithReason {
+                    reason: String::from_utf8_lossy(&reason).into_owned(),
+                }),
+            ),
+            _ => Err(PoapError::ERC721InvalidReceiver(ERC721InvalidReceiver {
+                receiver: to,
+            })),
+        }
+    }
+
+    /// Notifies the event's mint hook, if any, of a new badge. A failing hook
+    /// reverts the mint only if the organizer required it to succeed.
+    fn call_mint_hook(
+        &mut self,
+        event_id: U256,
+        token_id: U256,
+        recipient: Address,
+    ) -> Result<(), PoapError> {
+        let hook = self.event_mint_hook.get(event_id);
+        if hook.is_zero() {
+            return Ok(());
+        }
+
+        let context = Call::new_mutating(self).gas(MINT_HOOK_GAS_STIPEND);
+        let result = IPoapMintHook::new(hook).on_poap_minted(
+            self.vm(),
+            context,
+            event_id,
+            token_id,
+            recipient,
+        );
+        if result.is_err() {
+            if self.event_mint_hook_required.get(event_id) {
+                return Err(PoapError::MintHookReverted(MintHookReverted {
+                    eventId: event_id,
+                    hook,
+                }));
+            }
+            log(
+                self.vm(),
+                MintHookFailed {
+                    eventId: event_id,
+                    tokenId: token_id,
+                    hook,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// The default role is minted by the event's minters. Other roles are
+    /// minted by the organizer and the role's own minters. The owner can
+    /// mint any role.
+    fn ensure_can_mint(&self, event_id: U256, role_id: U256, to: Address) -> Result<(), PoapError> {
+        let sender = self.msg_sender();
+        if role_id == U256::ZERO {
+            let is_minter = self.event_minters.get(event_id).get(sender);
+            if !is_minter && self.ensure_owner().is_err() {
+                return Err(PoapError::NotEventMinter(NotEventMinter {
+                    eventId: event_id,
+                    account: sender,
+                }));
+            }
+        } else {
+            let is_minter = sender == self.event_organizer.get(event_id)
+                || self.role_minters.get(event_id).get(role_id).get(sender);
+            if !is_minter && self.ensure_owner().is_err() {
+                return Err(PoapError::NotRoleMinter(NotRoleMinter {
+                    eventId: event_id,
+                    roleId: role_id,
+                    account: sender,
+                }));
+            }
+        }
+
+        self.ensure_event_exists(event_id)?;
+        self.ensure_role_exists(event_id, role_id)?;
+        if !self.event_active.get(event_id) {
+            return Err(PoapError::EventInactive(EventInactive {
+                eventId: event_id,
+            }));
+        }
+        if self.event_attendance.get(event_id).get(to) {
+            return Err(PoapError::AlreadyAttended(AlreadyAttended {
+                eventId: event_id,
+                attendee: to,
+            }));
+        }
+        self.ensure_session_threshold_met(event_id, to)?;
+        let supply_cap = self.event_role_cap.get(event_id).get(role_id);
+        if supply_cap != U256::ZERO
+            && self.event_role_supply.get(event_id).get(role_id) >= supply_cap
+        {
+            return Err(PoapError::RoleSupplyExceeded(RoleSupplyExceeded {
+                eventId: event_id,
+                roleId: role_id,
+                supplyCap: supply_cap,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Decodes a relayed call and runs it. `msg_sender` already returns the
+    /// signer.
+    fn dispatch_forwarded(&mut self, call: &[u8]) -> Result<Vec<u8>, PoapError> {
+        let invalid = |_| PoapError::InvalidForwardedCall(InvalidForwardedCall {});
+        let selector = [call[0], call[1], call[2], call[3]];
+        let output = match selector {
+            mintTokenCall::SELECTOR => {
+                let args = mintTokenCall::abi_decode(call, true).map_err(invalid)?;
+                self.mint_token(args.eventId, args.to)?.abi_encode()
+            }
+            mintTokenSafeCall::SELECTOR => {
+                let args = mintTokenSafeCall::abi_decode(call, true).map_err(invalid)?;
+                self.mint_token_safe(args.eventId, args.to, args.data.to_vec().into())?
+                    .abi_encode()
+            }
+            mintTokenWithRoleCall::SELECTOR => {
+                let args = mintTokenWithRoleCall::abi_decode(call, true).map_err(invalid)?;
+                self.mint_token_with_role(args.eventId, args.to, args.roleId)?
+                    .abi_encode()
+            }
+            revokeBadgeCall::SELECTOR => {
+                let args = revokeBadgeCall::abi_decode(call, true).map_err(invalid)?;
+                self.revoke_badge(args.tokenId)?;
+                Vec::new()
+            }
+            claimAchievementCall::SELECTOR => {
+                let args = claimAchievementCall::abi_decode(call, true).map_err(invalid)?;
+                self.claim_achievement(args.achievementId)?.abi_encode()
+            }
+            createEventCall::SELECTOR => {
+                let args = createEventCall::abi_decode(call, true).map_err(invalid)?;
+                self.create_event(args.name, args.organizer)?.abi_encode()
+            }
+            createSessionEventCall::SELECTOR => {
+                let args = createSessionEventCall::abi_decode(call, true).map_err(invalid)?;
+                self.create_session_event(
+                    args.name,
+                    args.organizer,
+                    args.sessionThreshold,
+                    args.autoMint,
+                )?
+                .abi_encode()
+            }
+            addEventSessionCall::SELECTOR => {
+                let args = addEventSessionCall::abi_decode(call, true).map_err(invalid)?;
+                self.add_event_session(args.eventId, args.name)?
+                    .abi_encode()
+            }
+            checkInCall::SELECTOR => {
+                let args = checkInCall::abi_decode(call, true).map_err(invalid)?;
+                self.check_in(args.eventId, args.sessionId, args.attendee)?
+                    .abi_encode()
+            }
+            claimEventBadgeCall::SELECTOR => {
+                let args = claimEventBadgeCall::abi_decode(call, true).map_err(invalid)?;
+                self.claim_event_badge(args.eventId)?.abi_encode()
+            }
+            claimWithProofCall::SELECTOR => {
+                let args = claimWithProofCall::abi_decode(call, true).map_err(invalid)?;
+                self.claim_with_proof(args.eventId, args.proof)?
+                    .abi_encode()
+            }
+            addEventMinterCall::SELECTOR => {
+                let args = addEventMinterCall::abi_decode(call, true).map_err(invalid)?;
+                self.add_event_minter(args.eventId, args.minter)?;
+                Vec::new()
+            }
+            addEventRoleCall::SELECTOR => {
+                let args = addEventRoleCall::abi_decode(call, true).map_err(invalid)?;
+                self.add_event_role(args.eventId, args.name, args.supplyCap)?
+                    .abi_encode()
+            }
+            setRoleMinterCall::SELECTOR => {
+                let args = setRoleMinterCall::abi_decode(call, true).map_err(invalid)?;
+                self.set_role_minter(args.eventId, args.roleId, args.minter, args.allowed)?;
+                Vec::new()
+            }
+            _ => {
+                return Err(PoapError::UnsupportedForwardedCall(
+                    UnsupportedForwardedCall {
+                        selector: selector.into(),
+                    },
+                ))
+            }
+        };
+        Ok(output)
+    }
+
+    fn event_view(&self, event_id: U256) -> EventView {
+        (
+            event_id,
+            self.event_name.get(event_id).get_string(),
+            self.event_organizer.get(event_id),
+            self.event_active.get(event_id),
+            self.live_attendee_count(event_id),
+        )
+    }
+
+    fn holder_badge_view(&self, token_id: U256) -> HolderBadgeView {
+        let event_id = self.token_event.get(token_id);
+        let name = if event_id & ACHIEVEMENT_NAMESPACE != U256::ZERO {
+            self.achievement_name
+                .get(event_id ^ ACHIEVEMENT_NAMESPACE)
+                .get_string()
+        } else {
+            self.event_name.get(event_id).get_string()
+        };
+        (token_id, event_id, name)
+    }
+
+    fn add_event_attendee(&mut self, event_id: U256, attendee: Address) {
+        let mut attendees = self.event_attendees.setter(event_id);
+        self.event_attendee_index
+            .setter(event_id)
+            .setter(attendee)
+            .set(U256::from(attendees.len()));
+        attendees.push(attendee);
+    }
+
+    /// Marks `attendee`'s entry as revoked by zeroing it in place, so the
+    /// entries after it keep their indices and pages stay stable.
+    fn remove_event_attendee(&mut self, event_id: U256, attendee: Address) {
+        let index = self.event_attendee_index.get(event_id).get(attendee);
+        if let Some(mut slot) = self.event_attendees.setter(event_id).setter(index) {
+            slot.set(Address::ZERO);
+        }
+        self.event_attendee_index.setter(event_id).delete(attendee);
+
+        let revoked = self.event_revoked_attendees.get(event_id);
+        self.event_revoked_attendees
+            .setter(event_id)
+            .set(revoked + U256::from(1));
+    }
+
+    fn live_attendee_count(&self, event_id: U256) -> U256 {
+        U256::from(self.event_attendees.get(event_id).len())
+            - self.event_revoked_attendees.get(event_id)
+    }
+
+    fn mint_achievement_internal(
+        &mut self,
+        achievement_id: U256,
+        recipient: Address,
+    ) -> Result<U256, PoapError> {
+        let new_token_id = self.mint_next_token(recipient)?;
+
+        self.token_event
+            .setter(new_token_id)
+            .set(ACHIEVEMENT_NAMESPACE | achievement_id);
+        self.achievement_claimed
+            .setter(achievement_id)
+            .setter(recipient)
+            .set(true);
+
+        Ok(new_token_id)
+    }
+
+    fn achievement_progress(&self, achievement_id: U256, account: Address) -> U256 {
+        let events = self.achievement_events.get(achievement_id);
+        let mut count = U256::ZERO;
+        for i in 0..events.len() {
+            let event_id = events.get(i).unwrap_or_default();
+            if self.event_attendance.get(event_id).get(account) {
+                count += U256::from(1);
+            }
+        }
+        count
+    }
 }
 
 #[public]
-#[implements(IErc721Metadata<Error = erc721::Error>, IErc165)]
+#[implements(
+    IErc721Metadata<Error = erc721::Error>,
+    IErc721Enumerable<Error = PoapError>,
+    IPoapAttendance,
+    IErc165
+)]
 impl Poap {
+    /// Locks the implementation. State lives in the proxy, which calls
+    /// `initialize` instead, so the implementation itself is never set up.
     #[constructor]
-    fn constructor(&mut self, name: String, symbol: String, _base_uri: String, owner: Address) {
+    fn constructor(&mut self) {
+        self.initialized.set(true);
+    }
+
+    /// Sets up a fresh deployment. Takes the place of a constructor, which a
+    /// proxy never runs, and can only be called once.
+    fn initialize(
+        &mut self,
+        name: String,
+        symbol: String,
+        _base_uri: String,
+        owner: Address,
+    ) -> Result<(), PoapError> {
+        if self.initialized.get() {
+            return Err(PoapError::AlreadyInitialized(AlreadyInitialized {}));
+        }
+        self.initialized.set(true);
+
         self.metadata.constructor(name, symbol);
         self.owner._transfer_ownership(owner);
         self.last_token_id.set(U256::ZERO);
         self.last_event_id.set(U256::ZERO);
+
+        Ok(())
     }
 
     fn create_event(&mut self, name: String, organizer: Address) -> Result<U256, PoapError> {
@@ -113,36 +849,520 @@ impl Poap {
             .setter(new_event_id)
             .setter(organizer)
             .set(true);
+        self.organizer_events.setter(organizer).push(new_event_id);
+
+        log(
+            self.vm(),
+            EventCreated {
+                eventId: new_event_id,
+                organizer,
+                name,
+            },
+        );
 
         Ok(new_event_id)
     }
 
     fn add_event_minter(&mut self, event_id: U256, minter: Address) -> Result<(), PoapError> {
         self.ensure_owner()?;
-
-        if event_id > self.last_event_id.get() || event_id == U256::ZERO {
-            return Err(PoapError::InvalidOperation(InvalidOperation {}));
-        }
+        self.ensure_event_exists(event_id)?;
 
         self.event_minters.setter(event_id).setter(minter).set(true);
 
+        log(
+            self.vm(),
+            EventMinterAdded {
+                eventId: event_id,
+                minter,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Registers `hook` to receive `onPoapMinted` after every badge of
+    /// `event_id` is minted, replacing any previous hook. The zero address
+    /// removes it. With `revert_on_failure`, a reverting hook reverts the
+    /// mint; otherwise the failure is logged as `MintHookFailed`. Only the
+    /// event's organizer or the owner can set it.
+    ///
+    /// The hook runs once the badge is fully recorded, so it can call back
+    /// into `Poap`, e.g. to check `hasAttended` before accruing points.
+    fn set_event_mint_hook(
+        &mut self,
+        event_id: U256,
+        hook: Address,
+        revert_on_failure: bool,
+    ) -> Result<(), PoapError> {
+        self.ensure_event_exists(event_id)?;
+        self.ensure_organizer_or_owner(event_id)?;
+
+        self.event_mint_hook.setter(event_id).set(hook);
+        self.event_mint_hook_required
+            .setter(event_id)
+            .set(revert_on_failure);
+
+        log(
+            self.vm(),
+            EventMintHookSet {
+                eventId: event_id,
+                hook,
+                revertOnFailure: revert_on_failure,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Adds a badge role such as speaker or volunteer to `event_id` and
+    /// returns its id. Role ids start at 1; role 0 is the default attendee
+    /// role. A `supply_cap` of 0 leaves the role uncapped. Only the event's
+    /// organizer or the owner can add roles.
+    fn add_event_role(
+        &mut self,
+        event_id: U256,
+        name: String,
+        supply_cap: U256,
+    ) -> Result<U256, PoapError> {
+        self.ensure_event_exists(event_id)?;
+        self.ensure_organizer_or_owner(event_id)?;
+
+        let role_id = self.event_role_count.get(event_id) + U256::from(1);
+        self.event_role_count.setter(event_id).set(role_id);
+        self.event_role_name
+            .setter(event_id)
+            .setter(role_id)
+            .set_str(&name);
+        self.event_role_cap
+            .setter(event_id)
+            .setter(role_id)
+            .set(supply_cap);
+
+        log(
+            self.vm(),
+            EventRoleAdded {
+                eventId: event_id,
+                roleId: role_id,
+                name,
+                supplyCap: supply_cap,
+            },
+        );
+
+        Ok(role_id)
+    }
+
+    /// Allows or disallows `minter` to mint badges of `role_id`. Only the
+    /// event's organizer or the owner can set role minters.
+    fn set_role_minter(
+        &mut self,
+        event_id: U256,
+        role_id: U256,
+        minter: Address,
+        allowed: bool,
+    ) -> Result<(), PoapError> {
+        self.ensure_event_exists(event_id)?;
+        self.ensure_organizer_or_owner(event_id)?;
+        self.ensure_role_exists(event_id, role_id)?;
+
+        self.role_minters
+            .setter(event_id)
+            .setter(role_id)
+            .setter(minter)
+            .set(allowed);
+
+        log(
+            self.vm(),
+            RoleMinterSet {
+                eventId: event_id,
+                roleId: role_id,
+                minter,
+                allowed,
+            },
+        );
+
         Ok(())
     }
 
     fn mint_token(&mut self, event_id: U256, to: Address) -> Result<U256, PoapError> {
-        let is_minter = self.event_minters.get(event_id).get(self.vm().msg_sender());
+        self.ensure_can_mint(event_id, U256::ZERO, to)?;
+        self.mint_badge_internal(event_id, U256::ZERO, to, None)
+    }
+
+    /// Mints a badge of `role_id` for `event_id` to `to` and returns the new
+    /// token id. An attendee holds at most one badge per event, whatever its
+    /// role.
+    fn mint_token_with_role(
+        &mut self,
+        event_id: U256,
+        to: Address,
+        role_id: U256,
+    ) -> Result<U256, PoapError> {
+        self.ensure_can_mint(event_id, role_id, to)?;
+        self.mint_badge_internal(event_id, role_id, to, None)
+    }
+
+    fn mint_token_safe(
+        &mut self,
+        event_id: U256,
+        to: Address,
+        data: Bytes,
+    ) -> Result<U256, PoapError> {
+        self.ensure_can_mint(event_id, U256::ZERO, to)?;
+        self.mint_badge_internal(event_id, U256::ZERO, to, Some(&data))
+    }
+
+    fn revoke_badge(&mut self, token_id: U256) -> Result<(), PoapError> {
+        let sender = self.msg_sender();
+        let event_id = self.token_event.get(token_id);
+        let is_achievement = event_id & ACHIEVEMENT_NAMESPACE != U256::ZERO;
+
+        if is_achievement {
+            self.ensure_owner()?;
+        } else if !self.event_minters.get(event_id).get(sender) && self.ensure_owner().is_err() {
+            return Err(PoapError::NotEventMinter(NotEventMinter {
+                eventId: event_id,
+                account: sender,
+            }));
+        }
+
+        let holder = self.burn_token_internal(token_id)?;
+
+        if is_achievement {
+            self.achievement_claimed
+                .setter(event_id ^ ACHIEVEMENT_NAMESPACE)
+                .setter(holder)
+                .set(false);
+        } else {
+            self.event_attendance
+                .setter(event_id)
+                .setter(holder)
+                .set(false);
+            self.remove_event_attendee(event_id, holder);
 
-        if !is_minter && self.owner.only_owner().is_err() {
-            return Err(PoapError::UnauthorizedAction(UnauthorizedAction {}));
+            // Badges minted before roles existed were never counted.
+            let role_id = self.token_role.get(token_id);
+            let supply = self.event_role_supply.get(event_id).get(role_id);
+            self.event_role_supply
+                .setter(event_id)
+                .setter(role_id)
+                .set(supply.saturating_sub(U256::from(1)));
+            self.token_role.delete(token_id);
         }
+        self.token_event.delete(token_id);
+        self.token_minted_at.delete(token_id);
+        self.token_minted_block.delete(token_id);
+        self.token_minter.delete(token_id);
 
+        Ok(())
+    }
+
+    // ============ SESSIONS ============
+
+    /// Creates an event whose badge requires checking in to at least
+    /// `session_threshold` of its sessions. With `auto_mint`, the check-in
+    /// that meets the threshold mints the badge; otherwise the attendee
+    /// claims it with [`Self::claim_event_badge`]. Minters can also mint it
+    /// once the threshold is met. The threshold cannot be changed later.
+    fn create_session_event(
+        &mut self,
+        name: String,
+        organizer: Address,
+        session_threshold: U256,
+        auto_mint: bool,
+    ) -> Result<U256, PoapError> {
+        if session_threshold == U256::ZERO {
+            return Err(PoapError::InvalidSessionThreshold(
+                InvalidSessionThreshold {
+                    threshold: session_threshold,
+                },
+            ));
+        }
+
+        let event_id = self.create_event(name, organizer)?;
+        self.event_session_threshold
+            .setter(event_id)
+            .set(session_threshold);
+        self.event_auto_mint.setter(event_id).set(auto_mint);
+
+        Ok(event_id)
+    }
+
+    /// Adds a session to a session event and returns its id. Session ids
+    /// start at 1. Only the event's organizer or the owner can add sessions.
+    fn add_event_session(&mut self, event_id: U256, name: String) -> Result<U256, PoapError> {
+        self.ensure_event_exists(event_id)?;
+        self.ensure_organizer_or_owner(event_id)?;
+        if self.event_session_threshold.get(event_id) == U256::ZERO {
+            return Err(PoapError::NotSessionEvent(NotSessionEvent {
+                eventId: event_id,
+            }));
+        }
+
+        let session_id = self.event_session_count.get(event_id) + U256::from(1);
+        self.event_session_count.setter(event_id).set(session_id);
+        self.event_session_name
+            .setter(event_id)
+            .setter(session_id)
+            .set_str(&name);
+
+        log(
+            self.vm(),
+            SessionAdded {
+                eventId: event_id,
+                sessionId: session_id,
+                name,
+            },
+        );
+
+        Ok(session_id)
+    }
+
+    /// Records that `attendee` attended `session_id`. Returns the id of the
+    /// badge minted when this check-in meets the threshold of an auto-mint
+    /// event, zero otherwise. Only the event's minters or the owner can check
+    /// attendees in.
+    fn check_in(
+        &mut self,
+        event_id: U256,
+        session_id: U256,
+        attendee: Address,
+    ) -> Result<U256, PoapError> {
+        let sender = self.msg_sender();
+        if !self.event_minters.get(event_id).get(sender) && self.ensure_owner().is_err() {
+            return Err(PoapError::NotEventMinter(NotEventMinter {
+                eventId: event_id,
+                account: sender,
+            }));
+        }
+        self.ensure_event_exists(event_id)?;
         if !self.event_active.get(event_id) {
-            return Err(PoapError::InvalidOperation(InvalidOperation {}));
+            return Err(PoapError::EventInactive(EventInactive {
+                eventId: event_id,
+            }));
         }
-        if self.event_attendance.get(event_id).get(to) {
-            return Err(PoapError::InvalidOperation(InvalidOperation {}));
+        if session_id == U256::ZERO || session_id > self.event_session_count.get(event_id) {
+            return Err(PoapError::SessionNotFound(SessionNotFound {
+                eventId: event_id,
+                sessionId: session_id,
+            }));
+        }
+        if self
+            .session_check_ins
+            .get(event_id)
+            .get(session_id)
+            .get(attendee)
+        {
+            return Err(PoapError::AlreadyCheckedIn(AlreadyCheckedIn {
+                eventId: event_id,
+                sessionId: session_id,
+                attendee,
+            }));
+        }
+
+        self.session_check_ins
+            .setter(event_id)
+            .setter(session_id)
+            .setter(attendee)
+            .set(true);
+        let attended = self.attended_sessions.get(event_id).get(attendee) + U256::from(1);
+        self.attended_sessions
+            .setter(event_id)
+            .setter(attendee)
+            .set(attended);
+
+        log(
+            self.vm(),
+            SessionCheckedIn {
+                eventId: event_id,
+                sessionId: session_id,
+                attendee,
+            },
+        );
+
+        if self.event_auto_mint.get(event_id)
+            && attended == self.event_session_threshold.get(event_id)
+            && !self.event_attendance.get(event_id).get(attendee)
+        {
+            return self.mint_badge_internal(event_id, U256::ZERO, attendee, None);
+        }
+        Ok(U256::ZERO)
+    }
+
+    /// Mints the badge of a session event to the sender once they have
+    /// checked in to enough sessions. Returns the new token id.
+    fn claim_event_badge(&mut self, event_id: U256) -> Result<U256, PoapError> {
+        let claimant = self.msg_sender();
+
+        self.ensure_event_exists(event_id)?;
+        if self.event_session_threshold.get(event_id) == U256::ZERO {
+            return Err(PoapError::NotSessionEvent(NotSessionEvent {
+                eventId: event_id,
+            }));
         }
-        self.mint_badge_internal(event_id, to)
+        if !self.event_active.get(event_id) {
+            return Err(PoapError::EventInactive(EventInactive {
+                eventId: event_id,
+            }));
+        }
+        if self.event_attendance.get(event_id).get(claimant) {
+            return Err(PoapError::AlreadyAttended(AlreadyAttended {
+                eventId: event_id,
+                attendee: claimant,
+            }));
+        }
+        self.ensure_session_threshold_met(event_id, claimant)?;
+
+        self.mint_badge_internal(event_id, U256::ZERO, claimant, None)
+    }
+
+    /// Sets the root of `event_id`'s Merkle allowlist, built with
+    /// `poap-allowlist`. Listed attendees can then claim their badge with
+    /// `claim_with_proof`; the zero root disables allowlist claims. Only the
+    /// event's organizer or the owner can set it.
+    fn set_event_allowlist_root(&mut self, event_id: U256, root: B256) -> Result<(), PoapError> {
+        self.ensure_event_exists(event_id)?;
+        self.ensure_organizer_or_owner(event_id)?;
+
+        self.event_allowlist_root.setter(event_id).set(root);
+
+        log(
+            self.vm(),
+            EventAllowlistRootSet {
+                eventId: event_id,
+                root,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Mints the sender's badge of `event_id` if `proof` shows they are on
+    /// the event's allowlist. Returns the new token id.
+    fn claim_with_proof(&mut self, event_id: U256, proof: Vec<B256>) -> Result<U256, PoapError> {
+        let claimant = self.msg_sender();
+
+        self.ensure_event_exists(event_id)?;
+        if !self.event_active.get(event_id) {
+            return Err(PoapError::EventInactive(EventInactive {
+                eventId: event_id,
+            }));
+        }
+        if self.event_attendance.get(event_id).get(claimant) {
+            return Err(PoapError::AlreadyAttended(AlreadyAttended {
+                eventId: event_id,
+                attendee: claimant,
+            }));
+        }
+        let root = self.event_allowlist_root.get(event_id);
+        if root.is_zero() || !allowlist::verify(&proof, root, allowlist::leaf(event_id, claimant)) {
+            return Err(PoapError::InvalidAllowlistProof(InvalidAllowlistProof {
+                eventId: event_id,
+                account: claimant,
+            }));
+        }
+        self.ensure_session_threshold_met(event_id, claimant)?;
+
+        self.mint_badge_internal(event_id, U256::ZERO, claimant, None)
+    }
+
+    // ============ ACHIEVEMENTS ============
+
+    fn create_achievement(
+        &mut self,
+        name: String,
+        event_ids: Vec<U256>,
+        threshold: U256,
+    ) -> Result<U256, PoapError> {
+        self.ensure_owner()?;
+
+        let event_count = U256::from(event_ids.len());
+        if threshold == U256::ZERO || threshold > event_count {
+            return Err(PoapError::InvalidAchievementThreshold(
+                InvalidAchievementThreshold {
+                    threshold,
+                    eventCount: event_count,
+                },
+            ));
+        }
+        for (i, &event_id) in event_ids.iter().enumerate() {
+            self.ensure_event_exists(event_id)?;
+            if event_ids[..i].contains(&event_id) {
+                return Err(PoapError::DuplicateAchievementEvent(
+                    DuplicateAchievementEvent { eventId: event_id },
+                ));
+            }
+        }
+
+        let new_achievement_id = self.last_achievement_id.get() + U256::from(1);
+        self.last_achievement_id.set(new_achievement_id);
+
+        self.achievement_name
+            .setter(new_achievement_id)
+            .set_str(&name);
+        let mut events = self.achievement_events.setter(new_achievement_id);
+        for event_id in event_ids {
+            events.push(event_id);
+        }
+        self.achievement_threshold
+            .setter(new_achievement_id)
+            .set(threshold);
+        self.achievement_active.setter(new_achievement_id).set(true);
+
+        log(
+            self.vm(),
+            AchievementCreated {
+                achievementId: new_achievement_id,
+                name,
+                threshold,
+            },
+        );
+
+        Ok(new_achievement_id)
+    }
+
+    fn set_achievement_active(
+        &mut self,
+        achievement_id: U256,
+        active: bool,
+    ) -> Result<(), PoapError> {
+        self.ensure_owner()?;
+        self.ensure_achievement_exists(achievement_id)?;
+
+        self.achievement_active.setter(achievement_id).set(active);
+
+        Ok(())
+    }
+
+    fn claim_achievement(&mut self, achievement_id: U256) -> Result<U256, PoapError> {
+        let claimant = self.msg_sender();
+
+        self.ensure_achievement_exists(achievement_id)?;
+        if !self.achievement_active.get(achievement_id) {
+            return Err(PoapError::AchievementInactive(AchievementInactive {
+                achievementId: achievement_id,
+            }));
+        }
+        if self.achievement_claimed.get(achievement_id).get(claimant) {
+            return Err(PoapError::AchievementAlreadyClaimed(
+                AchievementAlreadyClaimed {
+                    achievementId: achievement_id,
+                    account: claimant,
+                },
+            ));
+        }
+
+        let attended = self.achievement_progress(achievement_id, claimant);
+        let threshold = self.achievement_threshold.get(achievement_id);
+        if attended < threshold {
+            return Err(PoapError::AchievementNotEarned(AchievementNotEarned {
+                achievementId: achievement_id,
+                account: claimant,
+                attended,
+                threshold,
+            }));
+        }
+
+        self.mint_achievement_internal(achievement_id, claimant)
     }
 
     // ============ SOULBOUND (Non-Transferable) ============
@@ -211,14 +1431,145 @@ impl Poap {
 
     // ============ ADMIN FUNCTIONS ============
 
-    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), ownable::Error> {
-        self.owner.transfer_ownership(new_owner)
+    fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), PoapError> {
+        self.ensure_owner()?;
+        if new_owner.is_zero() {
+            return Err(PoapError::OwnableInvalidOwner(OwnableInvalidOwner {
+                owner: Address::ZERO,
+            }));
+        }
+        self.owner._transfer_ownership(new_owner);
+        Ok(())
     }
 
     fn get_owner(&self) -> Address {
         self.owner.owner()
     }
 
+    // ============ META-TRANSACTIONS (ERC-2771) ============
+
+    /// Sets the forwarder allowed to relay calls signed by other accounts.
+    /// The zero address disables relaying.
+    fn set_trusted_forwarder(&mut self, forwarder: Address) -> Result<(), PoapError> {
+        self.ensure_owner()?;
+        self.trusted_forwarder.set(forwarder);
+        log(self.vm(), TrustedForwarderSet { forwarder });
+        Ok(())
+    }
+
+    fn get_trusted_forwarder(&self) -> Address {
+        self.trusted_forwarder.get()
+    }
+
+    fn is_trusted_forwarder(&self, forwarder: Address) -> bool {
+        !forwarder.is_zero() && forwarder == self.trusted_forwarder.get()
+    }
+
+    /// Runs a call relayed by the trusted forwarder on behalf of its signer.
+    ///
+    /// `calldata` is the relayed call with the signer's address appended, as
+    /// ERC-2771 forwarders send it. Only minting, claiming and event
+    /// administration can be relayed. Returns the relayed call's ABI-encoded
+    /// result.
+    ///
+    /// This deviates from ERC-2771, where the forwarder calls the target
+    /// function itself with the signer appended: the router decodes
+    /// arguments strictly and rejects such calldata, so a forwarder has to
+    /// wrap it in this entry point, as `poap-forwarder` does.
+    ///
+    /// The signer is kept in storage for the duration of the call, so every
+    /// relayed call pays for two extra `SSTORE`s, setting and clearing
+    /// `forwarded_sender`: about 22,000 gas up front, of which 19,900 is
+    /// refunded at the end of the transaction, within the EIP-3529 refund
+    /// cap. This lets the relayed functions read the signer through
+    /// `msg_sender` instead of taking it as an argument.
+    fn execute_forwarded(&mut self, calldata: Bytes) -> Result<Bytes, PoapError> {
+        let forwarder = self.vm().msg_sender();
+        if !self.is_trusted_forwarder(forwarder) {
+            return Err(PoapError::UntrustedForwarder(UntrustedForwarder {
+                forwarder,
+            }));
+        }
+        if calldata.len() < 4 + 20 {
+            return Err(PoapError::InvalidForwardedCall(InvalidForwardedCall {}));
+        }
+
+        // The forwarder may relay another call from within this one, so the
+        // outer signer is restored afterwards.
+        let (call, signer) = calldata.split_at(calldata.len() - 20);
+        let outer_signer = self.forwarded_sender.get();
+        self.forwarded_sender.set(Address::from_slice(signer));
+        let result = self.dispatch_forwarded(call);
+        self.forwarded_sender.set(outer_signer);
+
+        result.map(Bytes::from)
+    }
+
+    // ============ UPGRADES (UUPS) ============
+
+    /// Points the ERC-1967 proxy at `new_implementation` and, if `data` is
+    /// not empty, delegate-calls it with `data` (e.g. to migrate storage).
+    ///
+    /// `new_implementation` must report the ERC-1967 implementation slot from
+    /// `proxiableUUID`, which guards against upgrading to a contract that
+    /// cannot upgrade further. Can only be called through the proxy.
+    #[payable]
+    fn upgrade_to_and_call(
+        &mut self,
+        new_implementation: Address,
+        data: Bytes,
+    ) -> Result<(), PoapError> {
+        self.only_proxy()?;
+        self.ensure_owner()?;
+
+        let slot = IErc1822Proxiable::new(new_implementation)
+            .proxiable_uuid(self.vm(), Call::new())
+            .map_err(|_| {
+                PoapError::ERC1967InvalidImplementation(ERC1967InvalidImplementation {
+                    implementation: new_implementation,
+                })
+            })?;
+        if slot != IMPLEMENTATION_SLOT {
+            return Err(PoapError::UUPSUnsupportedProxiableUUID(
+                UUPSUnsupportedProxiableUUID { slot },
+            ));
+        }
+
+        StorageSlot::get_slot::<StorageAddress>(IMPLEMENTATION_SLOT).set(new_implementation);
+        log(
+            self.vm(),
+            Upgraded {
+                implementation: new_implementation,
+            },
+        );
+
+        if !data.is_empty() {
+            let context = Call::new_mutating(self);
+            unsafe { self.vm().delegate_call(&context, new_implementation, &data) }
+                .map_err(|_| PoapError::FailedCall(FailedCall {}))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the ERC-1967 implementation slot. Only answers on the
+    /// implementation itself, so a proxy can never be upgraded to point at
+    /// another proxy.
+    #[selector(name = "proxiableUUID")]
+    fn proxiable_uuid(&self) -> Result<B256, PoapError> {
+        if !self.get_implementation().is_zero() {
+            return Err(PoapError::UUPSUnauthorizedCallContext(
+                UUPSUnauthorizedCallContext {},
+            ));
+        }
+        Ok(IMPLEMENTATION_SLOT)
+    }
+
+    /// Returns the implementation the proxy delegates to.
+    fn get_implementation(&self) -> Address {
+        StorageSlot::get_slot::<StorageAddress>(IMPLEMENTATION_SLOT).get()
+    }
+
     fn get_event_name(&self, event_id: U256) -> String {
         self.event_name.get(event_id).get_string()
     }
@@ -235,6 +1586,73 @@ impl Poap {
         self.event_active.get(event_id)
     }
 
+    /// Returns the number of roles added to `event_id`, not counting the
+    /// default role 0.
+    fn get_event_role_count(&self, event_id: U256) -> U256 {
+        self.event_role_count.get(event_id)
+    }
+
+    fn get_event_role(&self, event_id: U256, role_id: U256) -> Result<EventRoleView, PoapError> {
+        self.ensure_event_exists(event_id)?;
+        self.ensure_role_exists(event_id, role_id)?;
+        Ok((
+            role_id,
+            self.role_name(event_id, role_id),
+            self.event_role_cap.get(event_id).get(role_id),
+            self.event_role_supply.get(event_id).get(role_id),
+        ))
+    }
+
+    fn is_role_minter(&self, event_id: U256, role_id: U256, minter: Address) -> bool {
+        self.role_minters.get(event_id).get(role_id).get(minter)
+    }
+
+    fn get_token_role(&self, token_id: U256) -> U256 {
+        self.token_role.get(token_id)
+    }
+
+    fn get_event_session_threshold(&self, event_id: U256) -> U256 {
+        self.event_session_threshold.get(event_id)
+    }
+
+    fn is_event_auto_mint(&self, event_id: U256) -> bool {
+        self.event_auto_mint.get(event_id)
+    }
+
+    fn get_event_session_count(&self, event_id: U256) -> U256 {
+        self.event_session_count.get(event_id)
+    }
+
+    fn get_event_session_name(&self, event_id: U256, session_id: U256) -> String {
+        self.event_session_name
+            .get(event_id)
+            .get(session_id)
+            .get_string()
+    }
+
+    fn has_checked_in(&self, event_id: U256, session_id: U256, attendee: Address) -> bool {
+        self.session_check_ins
+            .get(event_id)
+            .get(session_id)
+            .get(attendee)
+    }
+
+    fn get_attended_session_count(&self, event_id: U256, attendee: Address) -> U256 {
+        self.attended_sessions.get(event_id).get(attendee)
+    }
+
+    fn get_event_mint_hook(&self, event_id: U256) -> Address {
+        self.event_mint_hook.get(event_id)
+    }
+
+    fn is_event_mint_hook_required(&self, event_id: U256) -> bool {
+        self.event_mint_hook_required.get(event_id)
+    }
+
+    fn get_event_allowlist_root(&self, event_id: U256) -> B256 {
+        self.event_allowlist_root.get(event_id)
+    }
+
     fn get_last_token_id(&self) -> U256 {
         self.last_token_id.get()
     }
@@ -247,11 +1665,156 @@ impl Poap {
         self.token_event.get(token_id)
     }
 
+    /// Returns when, in which block and by whom `token_id` was minted. For
+    /// an achievement badge the minter is the claimant.
+    fn get_token_info(&self, token_id: U256) -> Result<TokenInfoView, PoapError> {
+        self.erc721.owner_of(token_id)?;
+        Ok((
+            self.token_event.get(token_id),
+            self.token_minted_at.get(token_id),
+            self.token_minted_block.get(token_id),
+            self.token_minter.get(token_id),
+        ))
+    }
+
+    fn get_event(&self, event_id: U256) -> Result<EventView, PoapError> {
+        self.ensure_event_exists(event_id)?;
+        Ok(self.event_view(event_id))
+    }
+
+    fn get_events(&self, offset: U256, limit: U256) -> Vec<EventView> {
+        let count = self.last_event_id.get().saturating_to::<usize>();
+        page_bounds(count, offset, limit)
+            .map(|i| self.event_view(U256::from(i + 1)))
+            .collect()
+    }
+
+    fn get_organizer_event_count(&self, organizer: Address) -> U256 {
+        U256::from(self.organizer_events.get(organizer).len())
+    }
+
+    fn get_events_by_organizer(
+        &self,
+        organizer: Address,
+        offset: U256,
+        limit: U256,
+    ) -> Vec<EventView> {
+        let event_ids = self.organizer_events.get(organizer);
+        page_bounds(event_ids.len(), offset, limit)
+            .map(|i| self.event_view(event_ids.get(i).unwrap_or_default()))
+            .collect()
+    }
+
+    /// Returns how many accounts currently hold the badge of `event_id`.
+    fn get_event_attendee_count(&self, event_id: U256) -> U256 {
+        self.live_attendee_count(event_id)
+    }
+
+    /// Returns the length of the attendee list [`Self::get_event_attendees`]
+    /// pages over, revoked entries included.
+    fn get_event_attendee_list_length(&self, event_id: U256) -> U256 {
+        U256::from(self.event_attendees.get(event_id).len())
+    }
+
+    /// Returns the attendees in `offset..offset + limit` of the event's
+    /// attendee list, in mint order. The list is append-only and skips
+    /// revoked entries, so a page can hold fewer than `limit` addresses but
+    /// never shifts when a badge is revoked. Page up to
+    /// [`Self::get_event_attendee_list_length`].
+    fn get_event_attendees(&self, event_id: U256, offset: U256, limit: U256) -> Vec<Address> {
+        let attendees = self.event_attendees.get(event_id);
+        page_bounds(attendees.len(), offset, limit)
+            .filter_map(|i| attendees.get(i))
+            .filter(|attendee| !attendee.is_zero())
+            .collect()
+    }
+
+    fn get_holder_badges(
+        &self,
+        owner: Address,
+        offset: U256,
+        limit: U256,
+    ) -> Result<Vec<HolderBadgeView>, erc721::Error> {
+        let balance = self.erc721.balance_of(owner)?.saturating_to::<usize>();
+        Ok(page_bounds(balance, offset, limit)
+            .map(|i| {
+                let token_id = self
+                    .enumerable
+                    .token_of_owner_by_index(owner, U256::from(i))
+                    .unwrap_or_default();
+                self.holder_badge_view(token_id)
+            })
+            .collect())
+    }
+
+    fn get_last_achievement_id(&self) -> U256 {
+        self.last_achievement_id.get()
+    }
+
+    fn get_achievement_name(&self, achievement_id: U256) -> String {
+        self.achievement_name.get(achievement_id).get_string()
+    }
+
+    fn get_achievement_events(&self, achievement_id: U256) -> Vec<U256> {
+        let events = self.achievement_events.get(achievement_id);
+        (0..events.len())
+            .map(|i| events.get(i).unwrap_or_default())
+            .collect()
+    }
+
+    fn get_achievement_threshold(&self, achievement_id: U256) -> U256 {
+        self.achievement_threshold.get(achievement_id)
+    }
+
+    fn is_achievement_active(&self, achievement_id: U256) -> bool {
+        self.achievement_active.get(achievement_id)
+    }
+
+    fn has_claimed_achievement(&self, achievement_id: U256, account: Address) -> bool {
+        self.achievement_claimed.get(achievement_id).get(account)
+    }
+
+    fn get_achievement_progress(&self, achievement_id: U256, account: Address) -> U256 {
+        self.achievement_progress(achievement_id, account)
+    }
+
+    fn is_achievement_token(&self, token_id: U256) -> bool {
+        self.token_event.get(token_id) & ACHIEVEMENT_NAMESPACE != U256::ZERO
+    }
+
     #[selector(name = "tokenURI")]
     pub fn token_uri_public(&self, token_id: U256) -> Result<String, erc721::Error> {
         self.erc721.owner_of(token_id)?;
         let event_id = self.token_event.get(token_id);
-        Ok(String::from("ipfs://") + &event_id.to_string() + "/" + &token_id.to_string())
+        let (_, _, name) = self.holder_badge_view(token_id);
+        let title = name.clone() + " #" + &token_id.to_string();
+
+        let metadata = if event_id & ACHIEVEMENT_NAMESPACE != U256::ZERO {
+            let achievement_id = event_id ^ ACHIEVEMENT_NAMESPACE;
+            let image = String::from("ipfs://achievements/")
+                + &achievement_id.to_string()
+                + "/"
+                + &token_id.to_string();
+            Metadata::new(
+                &title,
+                &(String::from("Awarded for ") + &name + "."),
+                &image,
+            )
+            .number_attribute("Achievement ID", achievement_id)
+        } else {
+            let image =
+                String::from("ipfs://") + &event_id.to_string() + "/" + &token_id.to_string();
+            let role = self.role_name(event_id, self.token_role.get(token_id));
+            Metadata::new(&title, &(String::from("Attended ") + &name + "."), &image)
+                .number_attribute("Event ID", event_id)
+                .attribute("Role", &role)
+        };
+
+        Ok(metadata
+            .date_attribute("Checked In", self.token_minted_at.get(token_id))
+            .number_attribute("Block", self.token_minted_block.get(token_id))
+            .attribute("Minter", &self.token_minter.get(token_id).to_checksum(None))
+            .into_data_uri())
     }
 }
 
@@ -272,10 +1835,66 @@ impl IErc721Metadata for Poap {
     }
 }
 
+#[public]
+impl IErc721Enumerable for Poap {
+    type Error = PoapError;
+
+    fn token_of_owner_by_index(&self, owner: Address, index: U256) -> Result<U256, Self::Error> {
+        Ok(self.enumerable.token_of_owner_by_index(owner, index)?)
+    }
+
+    fn total_supply(&self) -> U256 {
+        self.enumerable.total_supply()
+    }
+
+    fn token_by_index(&self, index: U256) -> Result<U256, Self::Error> {
+        Ok(self.enumerable.token_by_index(index)?)
+    }
+}
+
+#[public]
+impl IPoapAttendance for Poap {
+    fn has_attended(&self, event_id: U256, account: Address) -> bool {
+        self.event_attendance.get(event_id).get(account)
+    }
+
+    fn has_attended_any(&self, event_ids: Vec<U256>, account: Address) -> bool {
+        event_ids
+            .into_iter()
+            .any(|event_id| self.event_attendance.get(event_id).get(account))
+    }
+
+    fn attended_count(&self, account: Address) -> U256 {
+        // Each event badge is of a different event, so counting the held
+        // event badges counts the events.
+        let balance = self.erc721.balance_of(account).unwrap_or_default();
+        let mut count = U256::ZERO;
+        let mut index = U256::ZERO;
+        while index < balance {
+            let token_id = self
+                .enumerable
+                .token_of_owner_by_index(account, index)
+                .unwrap_or_default();
+            if self.token_event.get(token_id) & ACHIEVEMENT_NAMESPACE == U256::ZERO {
+                count += U256::from(1);
+            }
+            index += U256::from(1);
+        }
+        count
+    }
+}
+
 #[public]
 impl IErc165 for Poap {
     fn supports_interface(&self, interface_id: B32) -> bool {
         self.erc721.supports_interface(interface_id)
             || <Self as IErc721Metadata>::interface_id() == interface_id
+            || <Self as IErc721Enumerable>::interface_id() == interface_id
+            || <Self as IPoapAttendance>::interface_id() == interface_id
     }
 }
+
+#[cfg(test)]
+mod proptests;
+#[cfg(test)]
+mod tests;
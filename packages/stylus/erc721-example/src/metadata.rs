@@ -0,0 +1,115 @@
+//! On-chain ERC-721 metadata JSON, served by `tokenURI` as a base64 data URI.
+use alloc::string::String;
+
+use stylus_sdk::alloy_primitives::U256;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Builds a metadata document following the OpenSea metadata standard.
+pub struct Metadata {
+    json: String,
+    attribute_count: usize,
+}
+
+impl Metadata {
+    pub fn new(name: &str, description: &str, image: &str) -> Self {
+        let mut json = String::from("{\"name\":");
+        push_json_string(&mut json, name);
+        json.push_str(",\"description\":");
+        push_json_string(&mut json, description);
+        json.push_str(",\"image\":");
+        push_json_string(&mut json, image);
+        json.push_str(",\"attributes\":[");
+        Self {
+            json,
+            attribute_count: 0,
+        }
+    }
+
+    /// Adds an attribute with a string value.
+    pub fn attribute(self, trait_type: &str, value: &str) -> Self {
+        let mut quoted = String::new();
+        push_json_string(&mut quoted, value);
+        self.push_attribute(None, trait_type, &quoted)
+    }
+
+    /// Adds an attribute with a numeric value.
+    pub fn number_attribute(self, trait_type: &str, value: U256) -> Self {
+        self.push_attribute(None, trait_type, &value.to_string())
+    }
+
+    /// Adds a Unix timestamp attribute that marketplaces render as a date.
+    pub fn date_attribute(self, trait_type: &str, timestamp: U256) -> Self {
+        self.push_attribute(Some("date"), trait_type, &timestamp.to_string())
+    }
+
+    /// Returns the document as a `data:application/json;base64,` URI.
+    pub fn into_data_uri(mut self) -> String {
+        self.json.push_str("]}");
+        String::from("data:application/json;base64,") + &base64_encode(self.json.as_bytes())
+    }
+
+    fn push_attribute(mut self, display_type: Option<&str>, trait_type: &str, value: &str) -> Self {
+        if self.attribute_count > 0 {
+            self.json.push(',');
+        }
+        self.json.push('{');
+        if let Some(display_type) = display_type {
+            self.json.push_str("\"display_type\":");
+            push_json_string(&mut self.json, display_type);
+            self.json.push(',');
+        }
+        self.json.push_str("\"trait_type\":");
+        push_json_string(&mut self.json, trait_type);
+        self.json.push_str(",\"value\":");
+        self.json.push_str(value);
+        self.json.push('}');
+        self.attribute_count += 1;
+        self
+    }
+}
+
+/// Appends `value` to `out` as a JSON string literal. Event names are set by
+/// the owner, so quotes and control characters are escaped.
+fn push_json_string(out: &mut String, value: &str) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                out.push_str("\\u00");
+                out.push(HEX[(c as usize) >> 4] as char);
+                out.push(HEX[(c as usize) & 0xf] as char);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Encodes `bytes` as padded standard base64.
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
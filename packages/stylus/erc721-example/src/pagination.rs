@@ -0,0 +1,14 @@
+//! Offset/limit pagination shared by the list views of `Poap` and
+//! `PoapFactory`.
+use core::ops::Range;
+
+use stylus_sdk::alloy_primitives::U256;
+
+/// Clamps an `(offset, limit)` page request to the indices of a list of `len`.
+pub fn page_bounds(len: usize, offset: U256, limit: U256) -> Range<usize> {
+    let start = offset.saturating_to::<usize>().min(len);
+    let end = start
+        .saturating_add(limit.saturating_to::<usize>())
+        .min(len);
+    start..end
+}
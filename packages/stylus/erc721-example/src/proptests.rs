@@ -0,0 +1,263 @@
+//! Model-based property tests: random operation sequences are applied both
+//! to [`Poap`] and to a plain Rust model, and the outcomes must agree.
+use std::collections::BTreeSet;
+
+use motsu::prelude::*;
+use proptest::prelude::*;
+
+use super::*;
+
+const ACCOUNTS: usize = 4;
+const OWNER: usize = 0;
+
+fn account(index: usize) -> Address {
+    Address::repeat_byte(index as u8 + 1)
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    CreateEvent {
+        sender: usize,
+        organizer: usize,
+    },
+    AddEventMinter {
+        sender: usize,
+        event_id: u8,
+        minter: usize,
+    },
+    MintToken {
+        sender: usize,
+        event_id: u8,
+        to: usize,
+    },
+    TransferOwnership {
+        sender: usize,
+        new_owner: usize,
+    },
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    let account = 0..ACCOUNTS;
+    // Event ids deliberately include `0` and ids past the last created event.
+    let event_id = 0..6_u8;
+    prop_oneof![
+        (account.clone(), account.clone())
+            .prop_map(|(sender, organizer)| Op::CreateEvent { sender, organizer }),
+        (account.clone(), event_id.clone(), account.clone()).prop_map(
+            |(sender, event_id, minter)| Op::AddEventMinter {
+                sender,
+                event_id,
+                minter,
+            }
+        ),
+        (account.clone(), event_id, account.clone()).prop_map(|(sender, event_id, to)| {
+            Op::MintToken {
+                sender,
+                event_id,
+                to,
+            }
+        }),
+        (account.clone(), account)
+            .prop_map(|(sender, new_owner)| Op::TransferOwnership { sender, new_owner }),
+    ]
+}
+
+/// Outcome of an operation, reduced to what both implementations can report.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Ok(Option<U256>),
+    Err(&'static str),
+}
+
+fn error_name(err: &PoapError) -> &'static str {
+    match err {
+        PoapError::OwnableUnauthorizedAccount(_) => "OwnableUnauthorizedAccount",
+        PoapError::EventNotFound(_) => "EventNotFound",
+        PoapError::EventInactive(_) => "EventInactive",
+        PoapError::AlreadyAttended(_) => "AlreadyAttended",
+        PoapError::NotEventMinter(_) => "NotEventMinter",
+        _ => "Unexpected",
+    }
+}
+
+#[derive(Default)]
+struct ModelEvent {
+    minters: BTreeSet<usize>,
+    attendees: BTreeSet<usize>,
+}
+
+#[derive(Default)]
+struct Model {
+    owner: usize,
+    events: Vec<ModelEvent>,
+    token_events: Vec<usize>,
+}
+
+impl Model {
+    fn event_mut(&mut self, event_id: u8) -> Option<&mut ModelEvent> {
+        (event_id as usize)
+            .checked_sub(1)
+            .and_then(|index| self.events.get_mut(index))
+    }
+
+    fn apply(&mut self, op: &Op) -> Outcome {
+        match *op {
+            Op::CreateEvent { sender, organizer } => {
+                if sender != self.owner {
+                    return Outcome::Err("OwnableUnauthorizedAccount");
+                }
+                self.events.push(ModelEvent {
+                    minters: BTreeSet::from([organizer]),
+                    attendees: BTreeSet::new(),
+                });
+                Outcome::Ok(Some(U256::from(self.events.len())))
+            }
+            Op::AddEventMinter {
+                sender,
+                event_id,
+                minter,
+            } => {
+                if sender != self.owner {
+                    return Outcome::Err("OwnableUnauthorizedAccount");
+                }
+                match self.event_mut(event_id) {
+                    Some(event) => {
+                        event.minters.insert(minter);
+                        Outcome::Ok(None)
+                    }
+                    None => Outcome::Err("EventNotFound"),
+                }
+            }
+            Op::MintToken {
+                sender,
+                event_id,
+                to,
+            } => {
+                let is_owner = sender == self.owner;
+                let Some(event) = self.event_mut(event_id) else {
+                    return Outcome::Err(if is_owner {
+                        "EventNotFound"
+                    } else {
+                        "NotEventMinter"
+                    });
+                };
+                if !is_owner && !event.minters.contains(&sender) {
+                    return Outcome::Err("NotEventMinter");
+                }
+                if !event.attendees.insert(to) {
+                    return Outcome::Err("AlreadyAttended");
+                }
+                self.token_events.push(event_id as usize);
+                Outcome::Ok(Some(U256::from(self.token_events.len())))
+            }
+            Op::TransferOwnership { sender, new_owner } => {
+                if sender != self.owner {
+                    return Outcome::Err("OwnableUnauthorizedAccount");
+                }
+                self.owner = new_owner;
+                Outcome::Ok(None)
+            }
+        }
+    }
+}
+
+fn apply(contract: &Contract<Poap>, op: &Op) -> Outcome {
+    let result = match *op {
+        Op::CreateEvent { sender, organizer } => contract
+            .sender(account(sender))
+            .create_event(String::from("event"), account(organizer))
+            .map(Some),
+        Op::AddEventMinter {
+            sender,
+            event_id,
+            minter,
+        } => contract
+            .sender(account(sender))
+            .add_event_minter(U256::from(event_id), account(minter))
+            .map(|()| None),
+        Op::MintToken {
+            sender,
+            event_id,
+            to,
+        } => contract
+            .sender(account(sender))
+            .mint_token(U256::from(event_id), account(to))
+            .map(Some),
+        Op::TransferOwnership { sender, new_owner } => contract
+            .sender(account(sender))
+            .transfer_ownership(account(new_owner))
+            .map(|()| None),
+    };
+    match result {
+        Ok(value) => Outcome::Ok(value),
+        Err(err) => Outcome::Err(error_name(&err)),
+    }
+}
+
+fn check_invariants(contract: &Contract<Poap>, model: &Model) {
+    let poap = contract.sender(account(OWNER));
+    let last_token_id = poap.get_last_token_id();
+    let last_event_id = poap.get_last_event_id();
+
+    assert_eq!(last_token_id, U256::from(model.token_events.len()));
+    assert_eq!(poap.total_supply(), last_token_id);
+    assert_eq!(last_event_id, U256::from(model.events.len()));
+    assert_eq!(poap.get_owner(), account(model.owner));
+
+    for (index, &event_id) in model.token_events.iter().enumerate() {
+        let token_event = poap.get_token_event(U256::from(index + 1));
+        assert_eq!(token_event, U256::from(event_id));
+        assert!(token_event > U256::ZERO && token_event <= last_event_id);
+    }
+
+    for (index, event) in model.events.iter().enumerate() {
+        let event_id = U256::from(index + 1);
+        let attendees = poap.get_event_attendees(event_id, U256::ZERO, U256::from(ACCOUNTS));
+        let unique: BTreeSet<Address> = attendees.iter().copied().collect();
+        assert_eq!(unique.len(), attendees.len(), "attended twice");
+        assert_eq!(attendees.len(), event.attendees.len());
+
+        for holder in 0..ACCOUNTS {
+            assert_eq!(
+                poap.is_event_minter(event_id, account(holder)),
+                event.minters.contains(&holder)
+            );
+        }
+    }
+
+    for holder in 0..ACCOUNTS {
+        let expected = model
+            .events
+            .iter()
+            .filter(|event| event.attendees.contains(&holder))
+            .count();
+        assert_eq!(
+            poap.balance_of(account(holder)).motsu_unwrap(),
+            U256::from(expected)
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn poap_matches_model(ops in proptest::collection::vec(op_strategy(), 1..40)) {
+        let contract = Contract::<Poap>::new();
+        contract
+            .sender(account(OWNER))
+            .initialize(
+                String::from("POAP"),
+                String::from("POAP"),
+                String::from("ipfs://"),
+                account(OWNER),
+            )
+            .motsu_unwrap();
+        let mut model = Model::default();
+
+        for op in &ops {
+            let expected = model.apply(op);
+            let actual = apply(&contract, op);
+            prop_assert_eq!(&actual, &expected, "diverged on {:?}", op);
+            check_invariants(&contract, &model);
+        }
+    }
+}
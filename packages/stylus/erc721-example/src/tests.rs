@@ -0,0 +1,2695 @@
+use alloc::vec;
+
+use alloy_sol_types::{SolCall, SolError};
+use motsu::prelude::*;
+use stylus_sdk::{
+    alloy_primitives::{address, b256, fixed_bytes, uint},
+    ArbResult,
+};
+
+use super::*;
+
+const NAME: &str = "POAP";
+const SYMBOL: &str = "POAP";
+
+#[storage]
+struct Erc721ReceiverMock {
+    received_token_id: StorageU256,
+}
+
+unsafe impl TopLevelStorage for Erc721ReceiverMock {}
+
+#[public]
+impl Erc721ReceiverMock {
+    #[selector(name = "onERC721Received")]
+    fn on_erc721_received(
+        &mut self,
+        _operator: Address,
+        _from: Address,
+        token_id: U256,
+        _data: Bytes,
+    ) -> B32 {
+        self.received_token_id.set(token_id);
+        fixed_bytes!("150b7a02")
+    }
+
+    fn received_token_id(&self) -> U256 {
+        self.received_token_id.get()
+    }
+}
+
+sol_interface! {
+    interface IPoapViews {
+        function getTokenEvent(uint256 token_id) external view returns (uint256);
+        function hasAttended(uint256 event_id, address account) external view returns (bool);
+    }
+}
+
+/// Receiver that reads the badge back from the minting contract while
+/// accepting it.
+#[storage]
+struct ReadBackReceiverMock {
+    seen_event_id: StorageU256,
+    seen_attended: StorageBool,
+}
+
+unsafe impl TopLevelStorage for ReadBackReceiverMock {}
+
+#[public]
+impl ReadBackReceiverMock {
+    #[selector(name = "onERC721Received")]
+    fn on_erc721_received(
+        &mut self,
+        _operator: Address,
+        _from: Address,
+        token_id: U256,
+        _data: Bytes,
+    ) -> Result<B32, Vec<u8>> {
+        let poap = IPoapViews::new(self.vm().msg_sender());
+        let event_id = poap.get_token_event(self.vm(), Call::new(), token_id)?;
+        let attended = poap.has_attended(
+            self.vm(),
+            Call::new(),
+            event_id,
+            self.vm().contract_address(),
+        )?;
+        self.seen_event_id.set(event_id);
+        self.seen_attended.set(attended);
+        Ok(fixed_bytes!("150b7a02"))
+    }
+
+    fn seen_event_id(&self) -> U256 {
+        self.seen_event_id.get()
+    }
+
+    fn seen_attended(&self) -> bool {
+        self.seen_attended.get()
+    }
+}
+
+#[storage]
+struct NonReceiverMock {
+    value: StorageU256,
+}
+
+unsafe impl TopLevelStorage for NonReceiverMock {}
+
+#[public]
+impl NonReceiverMock {
+    fn value(&self) -> U256 {
+        self.value.get()
+    }
+}
+
+#[storage]
+struct MintHookMock {
+    fail: StorageBool,
+    calls: StorageU256,
+    last_token_id: StorageU256,
+    last_recipient: StorageAddress,
+}
+
+unsafe impl TopLevelStorage for MintHookMock {}
+
+#[public]
+impl MintHookMock {
+    fn set_fail(&mut self, fail: bool) {
+        self.fail.set(fail);
+    }
+
+    #[selector(name = "onPoapMinted")]
+    fn on_poap_minted(
+        &mut self,
+        _event_id: U256,
+        token_id: U256,
+        recipient: Address,
+    ) -> Result<(), Vec<u8>> {
+        if self.fail.get() {
+            return Err(b"hook failed".to_vec());
+        }
+        self.calls.set(self.calls.get() + U256::from(1));
+        self.last_token_id.set(token_id);
+        self.last_recipient.set(recipient);
+        Ok(())
+    }
+
+    fn calls(&self) -> U256 {
+        self.calls.get()
+    }
+
+    fn last_token_id(&self) -> U256 {
+        self.last_token_id.get()
+    }
+
+    fn last_recipient(&self) -> Address {
+        self.last_recipient.get()
+    }
+}
+
+/// ERC-1967 proxy like the one `Poap` is deployed behind. The constructor
+/// delegate-calls `data`, and every call is delegated to the implementation.
+#[storage]
+struct ProxyMock {}
+
+unsafe impl TopLevelStorage for ProxyMock {}
+
+#[public]
+impl ProxyMock {
+    #[constructor]
+    fn constructor(&mut self, implementation: Address, data: Bytes) -> Result<(), Vec<u8>> {
+        StorageSlot::get_slot::<StorageAddress>(IMPLEMENTATION_SLOT).set(implementation);
+        if !data.is_empty() {
+            self.fallback(&data)?;
+        }
+        Ok(())
+    }
+
+    #[fallback]
+    fn fallback(&mut self, calldata: &[u8]) -> ArbResult {
+        let implementation = StorageSlot::get_slot::<StorageAddress>(IMPLEMENTATION_SLOT).get();
+        let context = Call::new_mutating(self);
+        unsafe { self.vm().delegate_call(&context, implementation, calldata) }.map_err(Into::into)
+    }
+}
+
+/// Frozen copy of the storage layout `Poap` shipped with when it became
+/// upgradeable. Fields may only be appended to `Poap` after `initialized`;
+/// this struct must never change.
+#[storage]
+struct PoapLayoutV1 {
+    erc721: Erc721,
+    metadata: Erc721Metadata,
+    enumerable: Erc721Enumerable,
+    owner: Ownable,
+
+    last_token_id: StorageU256,
+    last_event_id: StorageU256,
+
+    token_event: StorageMap<U256, StorageU256>,
+    event_name: StorageMap<U256, StorageString>,
+    event_organizer: StorageMap<U256, StorageAddress>,
+    event_active: StorageMap<U256, StorageBool>,
+    event_minters: StorageMap<U256, StorageMap<Address, StorageBool>>,
+    event_attendance: StorageMap<U256, StorageMap<Address, StorageBool>>,
+    event_attendees: StorageMap<U256, StorageVec<StorageAddress>>,
+    event_attendee_index: StorageMap<U256, StorageMap<Address, StorageU256>>,
+    organizer_events: StorageMap<Address, StorageVec<StorageU256>>,
+
+    last_achievement_id: StorageU256,
+    achievement_name: StorageMap<U256, StorageString>,
+    achievement_events: StorageMap<U256, StorageVec<StorageU256>>,
+    achievement_threshold: StorageMap<U256, StorageU256>,
+    achievement_active: StorageMap<U256, StorageBool>,
+    achievement_claimed: StorageMap<U256, StorageMap<Address, StorageBool>>,
+
+    initialized: StorageBool,
+}
+
+unsafe impl TopLevelStorage for PoapLayoutV1 {}
+
+sol! {
+    function checkLayout(address owner, uint256 eventId, address attendee, uint256 tokenId);
+    function initialize(string name, string symbol, string base_uri, address owner);
+    function upgradeToAndCall(address newImplementation, bytes data);
+    function proxiableUUID() returns (bytes32 slot);
+    function getImplementation() returns (address implementation);
+}
+
+#[public]
+impl PoapLayoutV1 {
+    #[selector(name = "proxiableUUID")]
+    fn proxiable_uuid(&self) -> B256 {
+        IMPLEMENTATION_SLOT
+    }
+
+    /// Reverts unless state written by the current `Poap` reads back the
+    /// same through the V1 layout. Meant to be delegate-called.
+    fn check_layout(
+        &self,
+        owner: Address,
+        event_id: U256,
+        attendee: Address,
+        token_id: U256,
+    ) -> Result<(), Vec<u8>> {
+        let matches = self.initialized.get()
+            && self.owner.owner() == owner
+            && self.metadata.name() == NAME
+            && self.last_event_id.get() == event_id
+            && self.last_token_id.get() == token_id
+            && self.token_event.get(token_id) == event_id
+            && self.event_name.getter(event_id).get_string() == "ETHGlobal"
+            && self.event_organizer.get(event_id) == owner
+            && self.event_attendance.getter(event_id).get(attendee)
+            && self.event_attendees.getter(event_id).get(0) == Some(attendee)
+            && self.organizer_events.getter(owner).get(0) == Some(event_id)
+            && self.erc721.owner_of(token_id).ok() == Some(attendee)
+            && self.enumerable.total_supply() == U256::from(1);
+        if matches {
+            Ok(())
+        } else {
+            Err(Vec::new())
+        }
+    }
+}
+
+fn deploy(contract: &Contract<Poap>, owner: Address) {
+    contract
+        .sender(owner)
+        .initialize(
+            String::from(NAME),
+            String::from(SYMBOL),
+            String::from("ipfs://"),
+            owner,
+        )
+        .motsu_expect("first initialize should succeed");
+}
+
+/// Puts `proxy` in front of `implementation` and initializes it.
+fn deploy_proxy(proxy: &Contract<ProxyMock>, implementation: &Contract<Poap>, owner: Address) {
+    let data = initializeCall {
+        name: String::from(NAME),
+        symbol: String::from(SYMBOL),
+        base_uri: String::from("ipfs://"),
+        owner,
+    }
+    .abi_encode();
+    proxy
+        .sender(owner)
+        .constructor(implementation.address(), data.into())
+        .motsu_expect("proxy should initialize Poap");
+}
+
+/// Calls `Poap` through `proxy`, returning the revert data on failure.
+fn call_proxy(proxy: &Contract<ProxyMock>, sender: Address, call: impl SolCall) -> ArbResult {
+    proxy.sender(sender).fallback(&call.abi_encode())
+}
+
+fn proxy_implementation(proxy: &Contract<ProxyMock>, sender: Address) -> Address {
+    let output = call_proxy(proxy, sender, getImplementationCall {})
+        .motsu_expect("getImplementation should not revert");
+    getImplementationCall::abi_decode_returns(&output, true)
+        .expect("getImplementation should return an address")
+        .implementation
+}
+
+fn data_uri(json: &str) -> String {
+    String::from("data:application/json;base64,") + &metadata::base64_encode(json.as_bytes())
+}
+
+fn create_event(contract: &Contract<Poap>, owner: Address, organizer: Address) -> U256 {
+    contract
+        .sender(owner)
+        .create_event(String::from("ETHGlobal"), organizer)
+        .motsu_expect("owner should create an event")
+}
+
+fn mint(contract: &Contract<Poap>, minter: Address, event_id: U256, to: Address) -> U256 {
+    contract
+        .sender(minter)
+        .mint_token(event_id, to)
+        .motsu_expect("minter should mint a badge")
+}
+
+// ============ INITIALIZE ============
+
+#[motsu::test]
+fn initialize_sets_up_state(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+
+    assert_eq!(contract.sender(alice).name(), NAME);
+    assert_eq!(contract.sender(alice).symbol(), SYMBOL);
+    assert_eq!(contract.sender(alice).get_owner(), alice);
+    assert_eq!(contract.sender(alice).get_last_token_id(), U256::ZERO);
+    assert_eq!(contract.sender(alice).get_last_event_id(), U256::ZERO);
+    assert_eq!(contract.sender(alice).total_supply(), U256::ZERO);
+}
+
+#[motsu::test]
+fn constructor_locks_implementation(contract: Contract<Poap>, alice: Address) {
+    contract.sender(alice).constructor();
+
+    let err = contract
+        .sender(alice)
+        .initialize(
+            String::from(NAME),
+            String::from(SYMBOL),
+            String::from("ipfs://"),
+            alice,
+        )
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, PoapError::AlreadyInitialized(_)));
+    assert_eq!(contract.sender(alice).get_owner(), Address::ZERO);
+}
+
+#[motsu::test]
+fn proxy_initializes_in_its_own_storage(
+    proxy: Contract<ProxyMock>,
+    implementation: Contract<Poap>,
+    alice: Address,
+) {
+    implementation.sender(alice).constructor();
+    deploy_proxy(&proxy, &implementation, alice);
+
+    assert_eq!(
+        proxy_implementation(&proxy, alice),
+        implementation.address()
+    );
+    assert_eq!(implementation.sender(alice).get_owner(), Address::ZERO);
+}
+
+#[motsu::test]
+fn initialize_reverts_when_called_twice(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(bob)
+        .initialize(
+            String::from("Other"),
+            String::from("OTHER"),
+            String::from("ipfs://"),
+            bob,
+        )
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, PoapError::AlreadyInitialized(_)));
+    assert_eq!(contract.sender(alice).get_owner(), alice);
+    assert_eq!(contract.sender(alice).name(), NAME);
+}
+
+// ============ EVENTS ============
+
+#[motsu::test]
+fn create_event_stores_event(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+
+    let event_id = create_event(&contract, alice, bob);
+
+    assert_eq!(event_id, uint!(1_U256));
+    assert_eq!(contract.sender(alice).get_last_event_id(), event_id);
+    assert_eq!(contract.sender(alice).get_event_name(event_id), "ETHGlobal");
+    assert_eq!(contract.sender(alice).get_event_organizer(event_id), bob);
+    assert!(contract.sender(alice).is_event_active(event_id));
+    assert!(contract.sender(alice).is_event_minter(event_id, bob));
+    assert!(contract.emitted(&EventCreated {
+        eventId: event_id,
+        organizer: bob,
+        name: String::from("ETHGlobal"),
+    }));
+}
+
+#[motsu::test]
+fn create_event_increments_ids(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+
+    let first = create_event(&contract, alice, bob);
+    let second = create_event(&contract, alice, bob);
+
+    assert_eq!(first, uint!(1_U256));
+    assert_eq!(second, uint!(2_U256));
+    assert_eq!(contract.sender(alice).get_last_event_id(), second);
+}
+
+#[motsu::test]
+fn create_event_reverts_for_non_owner(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(bob)
+        .create_event(String::from("ETHGlobal"), bob)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::OwnableUnauthorizedAccount(OwnableUnauthorizedAccount { account })
+            if account == bob
+    ));
+    assert_eq!(contract.sender(alice).get_last_event_id(), U256::ZERO);
+}
+
+#[motsu::test]
+fn add_event_minter_grants_minting(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    charlie: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    assert!(!contract.sender(alice).is_event_minter(event_id, charlie));
+    contract
+        .sender(alice)
+        .add_event_minter(event_id, charlie)
+        .motsu_expect("owner should add a minter");
+
+    assert!(contract.sender(alice).is_event_minter(event_id, charlie));
+    assert!(contract.emitted(&EventMinterAdded {
+        eventId: event_id,
+        minter: charlie,
+    }));
+}
+
+#[motsu::test]
+fn add_event_minter_reverts_for_non_owner(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let err = contract
+        .sender(bob)
+        .add_event_minter(event_id, bob)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::OwnableUnauthorizedAccount(OwnableUnauthorizedAccount { account })
+            if account == bob
+    ));
+}
+
+#[motsu::test]
+fn add_event_minter_reverts_for_nonexistent_event(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+) {
+    deploy(&contract, alice);
+
+    for event_id in [U256::ZERO, uint!(1_U256)] {
+        let err = contract
+            .sender(alice)
+            .add_event_minter(event_id, bob)
+            .motsu_unwrap_err();
+
+        assert!(matches!(
+            err,
+            PoapError::EventNotFound(EventNotFound { eventId }) if eventId == event_id
+        ));
+    }
+}
+
+// ============ MINTING ============
+
+#[motsu::test]
+fn organizer_mints_badge(contract: Contract<Poap>, alice: Address, bob: Address, dave: Address) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let token_id = mint(&contract, bob, event_id, dave);
+
+    assert_eq!(token_id, uint!(1_U256));
+    assert_eq!(contract.sender(alice).get_last_token_id(), token_id);
+    assert_eq!(contract.sender(alice).get_token_event(token_id), event_id);
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+    assert_eq!(
+        contract.sender(alice).balance_of(dave).motsu_unwrap(),
+        uint!(1_U256)
+    );
+    assert!(contract.emitted(&BadgeMinted {
+        eventId: event_id,
+        tokenId: token_id,
+        attendee: dave,
+    }));
+}
+
+#[motsu::test]
+fn owner_mints_badge_without_minter_role(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let token_id = mint(&contract, alice, event_id, dave);
+
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+}
+
+#[motsu::test]
+fn added_minter_mints_badge(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    charlie: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    contract
+        .sender(alice)
+        .add_event_minter(event_id, charlie)
+        .motsu_unwrap();
+
+    let token_id = mint(&contract, charlie, event_id, dave);
+
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+}
+
+#[motsu::test]
+fn mint_token_reverts_for_non_minter(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    charlie: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let err = contract
+        .sender(charlie)
+        .mint_token(event_id, charlie)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::NotEventMinter(NotEventMinter { eventId, account })
+            if eventId == event_id && account == charlie
+    ));
+    assert_eq!(contract.sender(alice).get_last_token_id(), U256::ZERO);
+}
+
+#[motsu::test]
+fn minter_of_one_event_cannot_mint_another(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    charlie: Address,
+) {
+    deploy(&contract, alice);
+    create_event(&contract, alice, bob);
+    let other_event_id = create_event(&contract, alice, charlie);
+
+    let err = contract
+        .sender(bob)
+        .mint_token(other_event_id, bob)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::NotEventMinter(NotEventMinter { eventId, account })
+            if eventId == other_event_id && account == bob
+    ));
+}
+
+#[motsu::test]
+fn mint_token_reverts_for_nonexistent_event(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+    let event_id = uint!(7_U256);
+
+    let err = contract
+        .sender(alice)
+        .mint_token(event_id, alice)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::EventNotFound(EventNotFound { eventId }) if eventId == event_id
+    ));
+}
+
+#[motsu::test]
+fn mint_token_reverts_for_duplicate_attendee(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    mint(&contract, bob, event_id, dave);
+
+    let err = contract
+        .sender(bob)
+        .mint_token(event_id, dave)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::AlreadyAttended(AlreadyAttended { eventId, attendee })
+            if eventId == event_id && attendee == dave
+    ));
+    assert_eq!(
+        contract.sender(alice).balance_of(dave).motsu_unwrap(),
+        uint!(1_U256)
+    );
+}
+
+#[motsu::test]
+fn attendee_can_hold_badges_from_different_events(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let first = create_event(&contract, alice, bob);
+    let second = create_event(&contract, alice, bob);
+
+    mint(&contract, bob, first, dave);
+    mint(&contract, bob, second, dave);
+
+    assert_eq!(
+        contract.sender(alice).balance_of(dave).motsu_unwrap(),
+        uint!(2_U256)
+    );
+}
+
+#[motsu::test]
+fn mint_token_reverts_for_zero_address(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let err = contract
+        .sender(bob)
+        .mint_token(event_id, Address::ZERO)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::ERC721InvalidReceiver(erc721::ERC721InvalidReceiver { receiver })
+            if receiver == Address::ZERO
+    ));
+}
+
+#[motsu::test]
+fn mint_token_safe_mints_to_account(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let token_id = contract
+        .sender(bob)
+        .mint_token_safe(event_id, dave, vec![].into())
+        .motsu_unwrap();
+
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+}
+
+#[motsu::test]
+fn mint_token_safe_calls_receiver(
+    contract: Contract<Poap>,
+    receiver: Contract<Erc721ReceiverMock>,
+    alice: Address,
+    bob: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let token_id = contract
+        .sender(bob)
+        .mint_token_safe(event_id, receiver.address(), vec![0xab].into())
+        .motsu_unwrap();
+
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        receiver.address()
+    );
+    assert_eq!(receiver.sender(alice).received_token_id(), token_id);
+}
+
+#[motsu::test]
+fn mint_token_safe_records_badge_before_calling_receiver(
+    contract: Contract<Poap>,
+    receiver: Contract<ReadBackReceiverMock>,
+    alice: Address,
+    bob: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    contract
+        .sender(bob)
+        .mint_token_safe(event_id, receiver.address(), vec![].into())
+        .motsu_unwrap();
+
+    assert_eq!(receiver.sender(alice).seen_event_id(), event_id);
+    assert!(receiver.sender(alice).seen_attended());
+}
+
+#[motsu::test]
+fn mint_token_safe_reverts_for_non_receiver(
+    contract: Contract<Poap>,
+    non_receiver: Contract<NonReceiverMock>,
+    alice: Address,
+    bob: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let err = contract
+        .sender(bob)
+        .mint_token_safe(event_id, non_receiver.address(), vec![].into())
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::ERC721InvalidReceiver(erc721::ERC721InvalidReceiver { receiver })
+            if receiver == non_receiver.address()
+    ));
+}
+
+#[motsu::test]
+fn mint_token_reverts_for_inactive_event(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, alice);
+    // There is no public way to deactivate an event yet.
+    contract
+        .sender(alice)
+        .event_active
+        .setter(event_id)
+        .set(false);
+
+    let err = contract
+        .sender(alice)
+        .mint_token(event_id, alice)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::EventInactive(EventInactive { eventId }) if eventId == event_id
+    ));
+}
+
+// ============ REVOCATION ============
+
+#[motsu::test]
+fn minter_revokes_badge(contract: Contract<Poap>, alice: Address, bob: Address, dave: Address) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let token_id = mint(&contract, bob, event_id, dave);
+
+    contract
+        .sender(bob)
+        .revoke_badge(token_id)
+        .motsu_expect("minter should revoke a badge");
+
+    let err = contract.sender(alice).owner_of(token_id).motsu_unwrap_err();
+    assert!(matches!(err, erc721::Error::NonexistentToken(_)));
+    assert_eq!(contract.sender(alice).get_token_event(token_id), U256::ZERO);
+    assert_eq!(contract.sender(alice).total_supply(), U256::ZERO);
+    assert_eq!(
+        contract.sender(alice).get_event_attendee_count(event_id),
+        U256::ZERO
+    );
+
+    // The attendee can be issued a fresh badge afterwards.
+    mint(&contract, bob, event_id, dave);
+}
+
+#[motsu::test]
+fn revoke_badge_reverts_for_non_minter(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let token_id = mint(&contract, bob, event_id, dave);
+
+    let err = contract
+        .sender(dave)
+        .revoke_badge(token_id)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::NotEventMinter(NotEventMinter { eventId, account })
+            if eventId == event_id && account == dave
+    ));
+}
+
+#[motsu::test]
+fn revoke_badge_reverts_for_nonexistent_token(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+    let token_id = uint!(1_U256);
+
+    let err = contract
+        .sender(alice)
+        .revoke_badge(token_id)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::ERC721NonexistentToken(erc721::ERC721NonexistentToken { tokenId })
+            if tokenId == token_id
+    ));
+}
+
+// ============ MINT HOOKS ============
+
+#[motsu::test]
+fn set_event_mint_hook_is_organizer_or_owner(
+    contract: Contract<Poap>,
+    hook: Contract<MintHookMock>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    contract
+        .sender(bob)
+        .set_event_mint_hook(event_id, hook.address(), true)
+        .motsu_expect("organizer should set the hook");
+    assert_eq!(
+        contract.sender(alice).get_event_mint_hook(event_id),
+        hook.address()
+    );
+    assert!(contract.sender(alice).is_event_mint_hook_required(event_id));
+    assert!(contract.emitted(&EventMintHookSet {
+        eventId: event_id,
+        hook: hook.address(),
+        revertOnFailure: true,
+    }));
+
+    contract
+        .sender(alice)
+        .set_event_mint_hook(event_id, Address::ZERO, false)
+        .motsu_expect("owner should clear the hook");
+    assert!(contract
+        .sender(alice)
+        .get_event_mint_hook(event_id)
+        .is_zero());
+
+    let err = contract
+        .sender(dave)
+        .set_event_mint_hook(event_id, hook.address(), false)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::NotEventOrganizer(NotEventOrganizer { eventId, account })
+            if eventId == event_id && account == dave
+    ));
+
+    let err = contract
+        .sender(alice)
+        .set_event_mint_hook(uint!(99_U256), hook.address(), false)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::EventNotFound(_)));
+}
+
+#[motsu::test]
+fn mint_calls_event_mint_hook(
+    contract: Contract<Poap>,
+    hook: Contract<MintHookMock>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let other_event_id = create_event(&contract, alice, bob);
+    contract
+        .sender(bob)
+        .set_event_mint_hook(event_id, hook.address(), true)
+        .motsu_unwrap();
+
+    let token_id = mint(&contract, bob, event_id, dave);
+    mint(&contract, bob, other_event_id, dave);
+
+    assert_eq!(hook.sender(alice).calls(), uint!(1_U256));
+    assert_eq!(hook.sender(alice).last_token_id(), token_id);
+    assert_eq!(hook.sender(alice).last_recipient(), dave);
+}
+
+#[motsu::test]
+fn failing_required_mint_hook_reverts_mint(
+    contract: Contract<Poap>,
+    hook: Contract<MintHookMock>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    contract
+        .sender(bob)
+        .set_event_mint_hook(event_id, hook.address(), true)
+        .motsu_unwrap();
+    hook.sender(alice).set_fail(true);
+
+    let err = contract
+        .sender(bob)
+        .mint_token(event_id, dave)
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        PoapError::MintHookReverted(MintHookReverted { eventId, hook: failed })
+            if eventId == event_id && failed == hook.address()
+    ));
+}
+
+#[motsu::test]
+fn failing_optional_mint_hook_is_ignored(
+    contract: Contract<Poap>,
+    hook: Contract<MintHookMock>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    contract
+        .sender(bob)
+        .set_event_mint_hook(event_id, hook.address(), false)
+        .motsu_unwrap();
+    hook.sender(alice).set_fail(true);
+
+    let token_id = mint(&contract, bob, event_id, dave);
+
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+    assert!(contract.emitted(&MintHookFailed {
+        eventId: event_id,
+        tokenId: token_id,
+        hook: hook.address(),
+    }));
+    assert_eq!(hook.sender(alice).calls(), U256::ZERO);
+}
+
+// ============ ROLES ============
+
+fn add_role(
+    contract: &Contract<Poap>,
+    sender: Address,
+    event_id: U256,
+    name: &str,
+    supply_cap: U256,
+) -> U256 {
+    contract
+        .sender(sender)
+        .add_event_role(event_id, String::from(name), supply_cap)
+        .motsu_expect("organizer should add a role")
+}
+
+#[motsu::test]
+fn add_event_role_is_organizer_or_owner(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let speaker = add_role(&contract, bob, event_id, "Speaker", uint!(5_U256));
+    let volunteer = add_role(&contract, alice, event_id, "Volunteer", U256::ZERO);
+
+    assert_eq!((speaker, volunteer), (uint!(1_U256), uint!(2_U256)));
+    assert_eq!(
+        contract.sender(alice).get_event_role_count(event_id),
+        uint!(2_U256)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_role(event_id, speaker)
+            .motsu_unwrap(),
+        (speaker, String::from("Speaker"), uint!(5_U256), U256::ZERO)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_role(event_id, U256::ZERO)
+            .motsu_unwrap(),
+        (U256::ZERO, String::from("Attendee"), U256::ZERO, U256::ZERO)
+    );
+    assert!(contract.emitted(&EventRoleAdded {
+        eventId: event_id,
+        roleId: speaker,
+        name: String::from("Speaker"),
+        supplyCap: uint!(5_U256),
+    }));
+
+    let err = contract
+        .sender(dave)
+        .add_event_role(event_id, String::from("Sponsor"), U256::ZERO)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::NotEventOrganizer(_)));
+
+    let err = contract
+        .sender(alice)
+        .get_event_role(event_id, uint!(3_U256))
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::RoleNotFound(_)));
+}
+
+#[motsu::test]
+fn role_badges_need_role_minter(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let speaker = add_role(&contract, bob, event_id, "Speaker", U256::ZERO);
+    contract
+        .sender(alice)
+        .add_event_minter(event_id, carol)
+        .motsu_unwrap();
+
+    let err = contract
+        .sender(carol)
+        .mint_token_with_role(event_id, dave, speaker)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::NotRoleMinter(NotRoleMinter { eventId, roleId, account })
+            if eventId == event_id && roleId == speaker && account == carol
+    ));
+
+    contract
+        .sender(bob)
+        .set_role_minter(event_id, speaker, carol, true)
+        .motsu_unwrap();
+    assert!(contract
+        .sender(alice)
+        .is_role_minter(event_id, speaker, carol));
+    assert!(contract.emitted(&RoleMinterSet {
+        eventId: event_id,
+        roleId: speaker,
+        minter: carol,
+        allowed: true,
+    }));
+
+    let token_id = contract
+        .sender(carol)
+        .mint_token_with_role(event_id, dave, speaker)
+        .motsu_expect("role minter should mint a speaker badge");
+    assert_eq!(contract.sender(alice).get_token_role(token_id), speaker);
+    assert!(contract.sender(alice).has_attended(event_id, dave));
+
+    let organizer_token_id = contract
+        .sender(bob)
+        .mint_token_with_role(event_id, carol, speaker)
+        .motsu_expect("organizer should mint any role");
+    assert_eq!(
+        contract.sender(alice).get_token_role(organizer_token_id),
+        speaker
+    );
+
+    let err = contract
+        .sender(bob)
+        .mint_token_with_role(event_id, alice, uint!(2_U256))
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::RoleNotFound(_)));
+}
+
+#[motsu::test]
+fn role_supply_cap_is_enforced(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let speaker = add_role(&contract, bob, event_id, "Speaker", uint!(1_U256));
+
+    let token_id = contract
+        .sender(bob)
+        .mint_token_with_role(event_id, dave, speaker)
+        .motsu_unwrap();
+
+    let err = contract
+        .sender(bob)
+        .mint_token_with_role(event_id, carol, speaker)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::RoleSupplyExceeded(RoleSupplyExceeded { supplyCap, .. })
+            if supplyCap == uint!(1_U256)
+    ));
+    mint(&contract, bob, event_id, carol);
+
+    contract.sender(bob).revoke_badge(token_id).motsu_unwrap();
+    let (_, _, _, supply) = contract
+        .sender(alice)
+        .get_event_role(event_id, speaker)
+        .motsu_unwrap();
+    assert_eq!(supply, U256::ZERO);
+    contract
+        .sender(bob)
+        .mint_token_with_role(event_id, dave, speaker)
+        .motsu_expect("revoking should free the capped supply");
+}
+
+#[motsu::test]
+fn token_uri_includes_role(contract: Contract<Poap>, alice: Address, bob: Address, dave: Address) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let volunteer = add_role(&contract, bob, event_id, "Volunteer", U256::ZERO);
+    let token_id = contract
+        .sender(bob)
+        .mint_token_with_role(event_id, dave, volunteer)
+        .motsu_unwrap();
+
+    let (_, minted_at, block_number, _) = contract
+        .sender(alice)
+        .get_token_info(token_id)
+        .motsu_unwrap();
+
+    let json = format!(
+        r#"{{"name":"ETHGlobal #1","description":"Attended ETHGlobal.","image":"ipfs://1/1","attributes":[{{"trait_type":"Event ID","value":1}},{{"trait_type":"Role","value":"Volunteer"}},{{"display_type":"date","trait_type":"Checked In","value":{minted_at}}},{{"trait_type":"Block","value":{block_number}}},{{"trait_type":"Minter","value":"{}"}}]}}"#,
+        bob.to_checksum(None)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .token_uri_public(token_id)
+            .motsu_unwrap(),
+        data_uri(&json)
+    );
+}
+
+// ============ SESSIONS ============
+
+fn create_session_event(
+    contract: &Contract<Poap>,
+    owner: Address,
+    organizer: Address,
+    threshold: U256,
+    auto_mint: bool,
+) -> U256 {
+    let event_id = contract
+        .sender(owner)
+        .create_session_event(String::from("Devcon"), organizer, threshold, auto_mint)
+        .motsu_expect("owner should create a session event");
+    for name in ["Keynote", "Workshop", "Panel"] {
+        contract
+            .sender(organizer)
+            .add_event_session(event_id, String::from(name))
+            .motsu_expect("organizer should add a session");
+    }
+    event_id
+}
+
+fn check_in(
+    contract: &Contract<Poap>,
+    minter: Address,
+    event_id: U256,
+    session_id: U256,
+    attendee: Address,
+) -> U256 {
+    contract
+        .sender(minter)
+        .check_in(event_id, session_id, attendee)
+        .motsu_expect("minter should check the attendee in")
+}
+
+#[motsu::test]
+fn session_event_setup_is_validated(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .create_session_event(String::from("Devcon"), bob, U256::ZERO, true)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::InvalidSessionThreshold(_)));
+
+    let event_id = create_session_event(&contract, alice, bob, uint!(2_U256), true);
+    assert_eq!(
+        contract.sender(alice).get_event_session_threshold(event_id),
+        uint!(2_U256)
+    );
+    assert!(contract.sender(alice).is_event_auto_mint(event_id));
+    assert_eq!(
+        contract.sender(alice).get_event_session_count(event_id),
+        uint!(3_U256)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_session_name(event_id, uint!(2_U256)),
+        "Workshop"
+    );
+    assert!(contract.emitted(&SessionAdded {
+        eventId: event_id,
+        sessionId: uint!(3_U256),
+        name: String::from("Panel"),
+    }));
+
+    let plain_event_id = create_event(&contract, alice, bob);
+    let err = contract
+        .sender(bob)
+        .add_event_session(plain_event_id, String::from("Keynote"))
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::NotSessionEvent(_)));
+}
+
+#[motsu::test]
+fn check_in_auto_mints_at_threshold(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_session_event(&contract, alice, bob, uint!(2_U256), true);
+
+    assert_eq!(
+        check_in(&contract, bob, event_id, uint!(1_U256), dave),
+        U256::ZERO
+    );
+    assert!(!contract.sender(alice).has_attended(event_id, dave));
+    assert!(contract.emitted(&SessionCheckedIn {
+        eventId: event_id,
+        sessionId: uint!(1_U256),
+        attendee: dave,
+    }));
+
+    let token_id = check_in(&contract, bob, event_id, uint!(3_U256), dave);
+    assert_eq!(token_id, uint!(1_U256));
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+
+    assert_eq!(
+        check_in(&contract, bob, event_id, uint!(2_U256), dave),
+        U256::ZERO
+    );
+    assert_eq!(
+        contract.sender(alice).balance_of(dave).motsu_unwrap(),
+        uint!(1_U256)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_attended_session_count(event_id, dave),
+        uint!(3_U256)
+    );
+}
+
+#[motsu::test]
+fn check_in_is_validated(contract: Contract<Poap>, alice: Address, bob: Address, dave: Address) {
+    deploy(&contract, alice);
+    let event_id = create_session_event(&contract, alice, bob, uint!(2_U256), true);
+    check_in(&contract, bob, event_id, uint!(1_U256), dave);
+    assert!(contract
+        .sender(alice)
+        .has_checked_in(event_id, uint!(1_U256), dave));
+
+    let err = contract
+        .sender(bob)
+        .check_in(event_id, uint!(1_U256), dave)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::AlreadyCheckedIn(_)));
+
+    for session_id in [U256::ZERO, uint!(4_U256)] {
+        let err = contract
+            .sender(bob)
+            .check_in(event_id, session_id, dave)
+            .motsu_unwrap_err();
+        assert!(matches!(err, PoapError::SessionNotFound(_)));
+    }
+
+    let err = contract
+        .sender(dave)
+        .check_in(event_id, uint!(2_U256), dave)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::NotEventMinter(_)));
+}
+
+#[motsu::test]
+fn session_badge_is_claimable_at_threshold(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_session_event(&contract, alice, bob, uint!(2_U256), false);
+    check_in(&contract, bob, event_id, uint!(1_U256), dave);
+
+    let err = contract
+        .sender(dave)
+        .claim_event_badge(event_id)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::SessionThresholdNotMet(SessionThresholdNotMet { attended, threshold, .. })
+            if attended == uint!(1_U256) && threshold == uint!(2_U256)
+    ));
+    let err = contract
+        .sender(bob)
+        .mint_token(event_id, dave)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::SessionThresholdNotMet(_)));
+
+    assert_eq!(
+        check_in(&contract, bob, event_id, uint!(2_U256), dave),
+        U256::ZERO
+    );
+    let token_id = contract
+        .sender(dave)
+        .claim_event_badge(event_id)
+        .motsu_expect("attendee should claim at the threshold");
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+
+    let err = contract
+        .sender(dave)
+        .claim_event_badge(event_id)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::AlreadyAttended(_)));
+
+    check_in(&contract, bob, event_id, uint!(1_U256), carol);
+    check_in(&contract, bob, event_id, uint!(3_U256), carol);
+    mint(&contract, bob, event_id, carol);
+
+    let plain_event_id = create_event(&contract, alice, bob);
+    let err = contract
+        .sender(dave)
+        .claim_event_badge(plain_event_id)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::NotSessionEvent(_)));
+}
+
+// ============ ALLOWLIST ============
+
+#[motsu::test]
+fn allowlist_matches_standard_merkle_tree() {
+    // `StandardMerkleTree.of([[1, 0x11..11], [1, 0x22..22], [2, 0x33..33]],
+    // ["uint256", "address"])`, shared with the `poap-allowlist` tests.
+    let leaf = allowlist::leaf(
+        uint!(1_U256),
+        address!("1111111111111111111111111111111111111111"),
+    );
+    assert_eq!(
+        leaf,
+        b256!("c2ceb1212bc8227979fe9f384b22d0c77a49547981a7a40b4dbb2886abdfe7b6")
+    );
+
+    let root = b256!("04ae7f663bdad2d36b72e745482a05e9e5860e44e7cab625dc3dd8c1937c6c27");
+    assert!(allowlist::verify(
+        &[b256!(
+            "b1fb89aaa099fa54e70dd809a889439768410b8212f4e64cf6ce479a0e1b8933"
+        )],
+        root,
+        leaf,
+    ));
+    assert!(allowlist::verify(
+        &[
+            b256!("7b4e4429a12d7c7f52f97ac3310a7f766c8a424a830a3b5423010430bc468c18"),
+            leaf,
+        ],
+        root,
+        allowlist::leaf(
+            uint!(2_U256),
+            address!("3333333333333333333333333333333333333333"),
+        ),
+    ));
+    assert!(!allowlist::verify(&[leaf], root, leaf));
+
+    // The `@openzeppelin/merkle-tree` README example.
+    assert!(allowlist::verify(
+        &[b256!(
+            "b92c48e9d7abe27fd8dfd6b5dfdbfb1c9a463f80c712b66f3a5180a090cccafc"
+        )],
+        b256!("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77"),
+        b256!("eb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283"),
+    ));
+}
+
+#[motsu::test]
+fn set_event_allowlist_root_is_organizer_or_owner(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let root = allowlist::leaf(event_id, dave);
+
+    let err = contract
+        .sender(dave)
+        .set_event_allowlist_root(event_id, root)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::NotEventOrganizer(_)));
+
+    contract
+        .sender(bob)
+        .set_event_allowlist_root(event_id, root)
+        .motsu_unwrap();
+
+    assert_eq!(
+        contract.sender(dave).get_event_allowlist_root(event_id),
+        root
+    );
+    assert!(contract.emitted(&EventAllowlistRootSet {
+        eventId: event_id,
+        root,
+    }));
+}
+
+#[motsu::test]
+fn listed_account_claims_with_proof(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let err = contract
+        .sender(dave)
+        .claim_with_proof(event_id, vec![])
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::InvalidAllowlistProof(InvalidAllowlistProof { account, .. }) if account == dave
+    ));
+
+    let carol_leaf = allowlist::leaf(event_id, carol);
+    let dave_leaf = allowlist::leaf(event_id, dave);
+    contract
+        .sender(bob)
+        .set_event_allowlist_root(event_id, allowlist::hash_pair(carol_leaf, dave_leaf))
+        .motsu_unwrap();
+
+    let err = contract
+        .sender(alice)
+        .claim_with_proof(event_id, vec![dave_leaf])
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::InvalidAllowlistProof(_)));
+
+    let token_id = contract
+        .sender(dave)
+        .claim_with_proof(event_id, vec![carol_leaf])
+        .motsu_expect("listed account should claim");
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+    assert!(contract.sender(alice).has_attended(event_id, dave));
+
+    let err = contract
+        .sender(dave)
+        .claim_with_proof(event_id, vec![carol_leaf])
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::AlreadyAttended(_)));
+}
+
+// ============ ENUMERATION ============
+
+#[motsu::test]
+fn enumerable_tracks_mints_and_revocations(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let first = create_event(&contract, alice, bob);
+    let second = create_event(&contract, alice, bob);
+    let third = create_event(&contract, alice, bob);
+    let token_a = mint(&contract, bob, first, dave);
+    let token_b = mint(&contract, bob, second, dave);
+    let token_c = mint(&contract, bob, third, dave);
+
+    assert_eq!(contract.sender(alice).total_supply(), uint!(3_U256));
+    assert_eq!(
+        contract
+            .sender(alice)
+            .token_of_owner_by_index(dave, uint!(1_U256))
+            .motsu_unwrap(),
+        token_b
+    );
+
+    contract.sender(bob).revoke_badge(token_a).motsu_unwrap();
+
+    assert_eq!(contract.sender(alice).total_supply(), uint!(2_U256));
+    let owned: Vec<U256> = (0..2)
+        .map(|i| {
+            contract
+                .sender(alice)
+                .token_of_owner_by_index(dave, U256::from(i))
+                .motsu_unwrap()
+        })
+        .collect();
+    assert!(owned.contains(&token_b) && owned.contains(&token_c));
+    let all: Vec<U256> = (0..2)
+        .map(|i| {
+            contract
+                .sender(alice)
+                .token_by_index(U256::from(i))
+                .motsu_unwrap()
+        })
+        .collect();
+    assert!(all.contains(&token_b) && all.contains(&token_c));
+}
+
+#[motsu::test]
+fn enumerable_reverts_out_of_bounds(contract: Contract<Poap>, alice: Address, dave: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .token_of_owner_by_index(dave, U256::ZERO)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::ERC721OutOfBoundsIndex(ERC721OutOfBoundsIndex { owner, index })
+            if owner == dave && index == U256::ZERO
+    ));
+
+    let err = contract
+        .sender(alice)
+        .token_by_index(U256::ZERO)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::ERC721OutOfBoundsIndex(_)));
+}
+
+// ============ EVENT VIEWS ============
+
+#[motsu::test]
+fn event_attendees_are_listed_and_paginated(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    charlie: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let token_id = mint(&contract, bob, event_id, bob);
+    mint(&contract, bob, event_id, charlie);
+    mint(&contract, bob, event_id, dave);
+
+    assert_eq!(
+        contract.sender(alice).get_event_attendee_count(event_id),
+        uint!(3_U256)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_attendees(event_id, U256::ZERO, uint!(2_U256)),
+        vec![bob, charlie]
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_attendees(event_id, uint!(2_U256), uint!(10_U256)),
+        vec![dave]
+    );
+    assert!(contract
+        .sender(alice)
+        .get_event_attendees(event_id, uint!(5_U256), uint!(10_U256))
+        .is_empty());
+
+    contract.sender(bob).revoke_badge(token_id).motsu_unwrap();
+
+    assert_eq!(
+        contract.sender(alice).get_event_attendee_count(event_id),
+        uint!(2_U256)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_attendee_list_length(event_id),
+        uint!(3_U256)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_attendees(event_id, U256::ZERO, uint!(10_U256)),
+        vec![charlie, dave]
+    );
+    // Revoking leaves the entries after it in place.
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_attendees(event_id, uint!(2_U256), uint!(10_U256)),
+        vec![dave]
+    );
+
+    mint(&contract, bob, event_id, bob);
+
+    assert_eq!(
+        contract.sender(alice).get_event_attendee_count(event_id),
+        uint!(3_U256)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_event_attendees(event_id, U256::ZERO, uint!(10_U256)),
+        vec![charlie, dave, bob]
+    );
+}
+
+#[motsu::test]
+fn get_event_returns_all_fields(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    mint(&contract, bob, event_id, dave);
+
+    let event = contract.sender(alice).get_event(event_id).motsu_unwrap();
+
+    assert_eq!(
+        event,
+        (
+            event_id,
+            String::from("ETHGlobal"),
+            bob,
+            true,
+            uint!(1_U256)
+        )
+    );
+}
+
+#[motsu::test]
+fn get_event_reverts_for_nonexistent_event(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .get_event(uint!(1_U256))
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, PoapError::EventNotFound(_)));
+}
+
+#[motsu::test]
+fn events_are_paginated_globally_and_by_organizer(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    charlie: Address,
+) {
+    deploy(&contract, alice);
+    create_event(&contract, alice, bob);
+    create_event(&contract, alice, charlie);
+    create_event(&contract, alice, bob);
+
+    let ids = |events: Vec<EventView>| events.into_iter().map(|e| e.0).collect::<Vec<_>>();
+
+    assert_eq!(
+        ids(contract
+            .sender(alice)
+            .get_events(uint!(1_U256), uint!(5_U256))),
+        vec![uint!(2_U256), uint!(3_U256)]
+    );
+    assert_eq!(
+        contract.sender(alice).get_organizer_event_count(bob),
+        uint!(2_U256)
+    );
+    assert_eq!(
+        ids(contract
+            .sender(alice)
+            .get_events_by_organizer(bob, U256::ZERO, uint!(5_U256))),
+        vec![uint!(1_U256), uint!(3_U256)]
+    );
+    assert!(contract
+        .sender(alice)
+        .get_events_by_organizer(alice, U256::ZERO, uint!(5_U256))
+        .is_empty());
+}
+
+#[motsu::test]
+fn get_holder_badges_lists_tokens_with_event_names(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let first = create_event(&contract, alice, bob);
+    let second = contract
+        .sender(alice)
+        .create_event(String::from("Devcon"), bob)
+        .motsu_unwrap();
+    let token_a = mint(&contract, bob, first, dave);
+    let token_b = mint(&contract, bob, second, dave);
+
+    let badges = contract
+        .sender(alice)
+        .get_holder_badges(dave, U256::ZERO, uint!(10_U256))
+        .motsu_unwrap();
+
+    assert_eq!(
+        badges,
+        vec![
+            (token_a, first, String::from("ETHGlobal")),
+            (token_b, second, String::from("Devcon")),
+        ]
+    );
+    assert!(contract
+        .sender(alice)
+        .get_holder_badges(bob, U256::ZERO, uint!(10_U256))
+        .motsu_unwrap()
+        .is_empty());
+}
+
+// ============ ACHIEVEMENTS ============
+
+#[motsu::test]
+fn achievement_is_claimed_after_threshold(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let events: Vec<U256> = (0..3)
+        .map(|_| create_event(&contract, alice, bob))
+        .collect();
+    let achievement_id = contract
+        .sender(alice)
+        .create_achievement(String::from("Regular"), events.clone(), uint!(2_U256))
+        .motsu_unwrap();
+
+    assert_eq!(
+        contract.sender(alice).get_last_achievement_id(),
+        achievement_id
+    );
+    assert!(contract.emitted(&AchievementCreated {
+        achievementId: achievement_id,
+        name: String::from("Regular"),
+        threshold: uint!(2_U256),
+    }));
+    assert_eq!(
+        contract.sender(alice).get_achievement_name(achievement_id),
+        "Regular"
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_achievement_events(achievement_id),
+        events
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_achievement_threshold(achievement_id),
+        uint!(2_U256)
+    );
+    assert!(contract.sender(alice).is_achievement_active(achievement_id));
+
+    mint(&contract, bob, events[0], dave);
+    let err = contract
+        .sender(dave)
+        .claim_achievement(achievement_id)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::AchievementNotEarned(AchievementNotEarned { attended, threshold, .. })
+            if attended == uint!(1_U256) && threshold == uint!(2_U256)
+    ));
+
+    mint(&contract, bob, events[2], dave);
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_achievement_progress(achievement_id, dave),
+        uint!(2_U256)
+    );
+    let token_id = contract
+        .sender(dave)
+        .claim_achievement(achievement_id)
+        .motsu_unwrap();
+
+    assert!(contract
+        .sender(alice)
+        .has_claimed_achievement(achievement_id, dave));
+    assert!(contract.sender(alice).is_achievement_token(token_id));
+    assert!(!contract.sender(alice).is_achievement_token(uint!(1_U256)));
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+
+    let err = contract
+        .sender(dave)
+        .claim_achievement(achievement_id)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::AchievementAlreadyClaimed(AchievementAlreadyClaimed { account, .. })
+            if account == dave
+    ));
+}
+
+#[motsu::test]
+fn create_achievement_validates_input(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+
+    let err = contract
+        .sender(bob)
+        .create_achievement(String::from("A"), vec![event_id], uint!(1_U256))
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::OwnableUnauthorizedAccount(_)));
+
+    for threshold in [U256::ZERO, uint!(2_U256)] {
+        let err = contract
+            .sender(alice)
+            .create_achievement(String::from("A"), vec![event_id], threshold)
+            .motsu_unwrap_err();
+        assert!(matches!(err, PoapError::InvalidAchievementThreshold(_)));
+    }
+
+    let err = contract
+        .sender(alice)
+        .create_achievement(String::from("A"), vec![uint!(9_U256)], uint!(1_U256))
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::EventNotFound(_)));
+
+    let err = contract
+        .sender(alice)
+        .create_achievement(String::from("A"), vec![event_id, event_id], uint!(1_U256))
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::DuplicateAchievementEvent(DuplicateAchievementEvent { eventId })
+            if eventId == event_id
+    ));
+}
+
+#[motsu::test]
+fn inactive_achievement_cannot_be_claimed(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    mint(&contract, bob, event_id, dave);
+    let achievement_id = contract
+        .sender(alice)
+        .create_achievement(String::from("A"), vec![event_id], uint!(1_U256))
+        .motsu_unwrap();
+
+    let err = contract
+        .sender(bob)
+        .set_achievement_active(achievement_id, false)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::OwnableUnauthorizedAccount(_)));
+
+    contract
+        .sender(alice)
+        .set_achievement_active(achievement_id, false)
+        .motsu_unwrap();
+    assert!(!contract.sender(alice).is_achievement_active(achievement_id));
+
+    let err = contract
+        .sender(dave)
+        .claim_achievement(achievement_id)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::AchievementInactive(_)));
+}
+
+#[motsu::test]
+fn unknown_achievement_reverts(contract: Contract<Poap>, alice: Address, dave: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .set_achievement_active(uint!(1_U256), true)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::AchievementNotFound(_)));
+
+    let err = contract
+        .sender(dave)
+        .claim_achievement(uint!(1_U256))
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::AchievementNotFound(_)));
+}
+
+#[motsu::test]
+fn achievement_badge_is_revoked_by_owner_only(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    mint(&contract, bob, event_id, dave);
+    let achievement_id = contract
+        .sender(alice)
+        .create_achievement(String::from("A"), vec![event_id], uint!(1_U256))
+        .motsu_unwrap();
+    let token_id = contract
+        .sender(dave)
+        .claim_achievement(achievement_id)
+        .motsu_unwrap();
+
+    let err = contract
+        .sender(bob)
+        .revoke_badge(token_id)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::OwnableUnauthorizedAccount(_)));
+
+    contract.sender(alice).revoke_badge(token_id).motsu_unwrap();
+    assert!(!contract
+        .sender(alice)
+        .has_claimed_achievement(achievement_id, dave));
+}
+
+// ============ SOULBOUND ============
+
+#[motsu::test]
+fn transfers_and_approvals_are_rejected(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let token_id = mint(&contract, bob, event_id, dave);
+
+    let err = contract
+        .sender(dave)
+        .transfer_from(dave, bob, token_id)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::UnauthorizedAction(_)));
+
+    let err = contract
+        .sender(dave)
+        .safe_transfer_from(dave, bob, token_id)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::UnauthorizedAction(_)));
+
+    let err = contract
+        .sender(dave)
+        .safe_transfer_from_with_data(dave, bob, token_id, vec![].into())
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::UnauthorizedAction(_)));
+
+    let err = contract
+        .sender(dave)
+        .approve(bob, token_id)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::UnauthorizedAction(_)));
+
+    let err = contract
+        .sender(dave)
+        .set_approval_for_all(bob, true)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::UnauthorizedAction(_)));
+
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+    assert_eq!(
+        contract.sender(alice).get_approved(token_id).motsu_unwrap(),
+        Address::ZERO
+    );
+    assert!(!contract.sender(alice).is_approved_for_all(dave, bob));
+}
+
+// ============ ERC-721 READS ============
+
+#[motsu::test]
+fn balance_of_reverts_for_zero_address(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .balance_of(Address::ZERO)
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, erc721::Error::InvalidOwner(_)));
+}
+
+#[motsu::test]
+fn owner_of_reverts_for_nonexistent_token(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .owner_of(uint!(1_U256))
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, erc721::Error::NonexistentToken(_)));
+}
+
+#[motsu::test]
+fn token_uri_formats_event_and_achievement_tokens(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let token_id = mint(&contract, bob, event_id, dave);
+    let achievement_id = contract
+        .sender(alice)
+        .create_achievement(String::from("A"), vec![event_id], uint!(1_U256))
+        .motsu_unwrap();
+    let achievement_token_id = contract
+        .sender(dave)
+        .claim_achievement(achievement_id)
+        .motsu_unwrap();
+
+    let (_, minted_at, block_number, _) = contract
+        .sender(alice)
+        .get_token_info(token_id)
+        .motsu_unwrap();
+    let check_in = format!(
+        r#"{{"display_type":"date","trait_type":"Checked In","value":{minted_at}}},{{"trait_type":"Block","value":{block_number}}}"#
+    );
+
+    let event_json = format!(
+        r#"{{"name":"ETHGlobal #1","description":"Attended ETHGlobal.","image":"ipfs://1/1","attributes":[{{"trait_type":"Event ID","value":1}},{{"trait_type":"Role","value":"Attendee"}},{check_in},{{"trait_type":"Minter","value":"{}"}}]}}"#,
+        bob.to_checksum(None)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .token_uri_public(token_id)
+            .motsu_unwrap(),
+        data_uri(&event_json)
+    );
+    assert_eq!(
+        contract.sender(alice).token_uri(token_id).motsu_unwrap(),
+        data_uri(&event_json)
+    );
+
+    let achievement_json = format!(
+        r#"{{"name":"A #2","description":"Awarded for A.","image":"ipfs://achievements/1/2","attributes":[{{"trait_type":"Achievement ID","value":1}},{check_in},{{"trait_type":"Minter","value":"{}"}}]}}"#,
+        dave.to_checksum(None)
+    );
+    assert_eq!(
+        contract
+            .sender(alice)
+            .token_uri_public(achievement_token_id)
+            .motsu_unwrap(),
+        data_uri(&achievement_json)
+    );
+}
+
+#[test]
+fn metadata_escapes_strings() {
+    let uri = Metadata::new("\"Rust\" \\ Stylus\n", "", "").into_data_uri();
+
+    assert_eq!(
+        uri,
+        data_uri(r#"{"name":"\"Rust\" \\ Stylus\n","description":"","image":"","attributes":[]}"#)
+    );
+}
+
+#[test]
+fn base64_encode_pads_output() {
+    assert_eq!(metadata::base64_encode(b"Man"), "TWFu");
+    assert_eq!(metadata::base64_encode(b"Ma"), "TWE=");
+    assert_eq!(metadata::base64_encode(b"M"), "TQ==");
+    assert_eq!(metadata::base64_encode(b""), "");
+}
+
+#[motsu::test]
+fn get_token_info_records_minter(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let token_id = mint(&contract, bob, event_id, dave);
+
+    let (info_event_id, _, _, minter) = contract
+        .sender(alice)
+        .get_token_info(token_id)
+        .motsu_unwrap();
+
+    assert_eq!(info_event_id, event_id);
+    assert_eq!(minter, bob);
+}
+
+#[motsu::test]
+fn get_token_info_reverts_for_revoked_token(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    let token_id = mint(&contract, bob, event_id, dave);
+    contract.sender(bob).revoke_badge(token_id).motsu_unwrap();
+
+    let err = contract
+        .sender(alice)
+        .get_token_info(token_id)
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, PoapError::ERC721NonexistentToken(_)));
+}
+
+#[motsu::test]
+fn token_uri_reverts_for_nonexistent_token(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .token_uri_public(uint!(1_U256))
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, erc721::Error::NonexistentToken(_)));
+}
+
+// ============ OWNERSHIP ============
+
+#[motsu::test]
+fn transfer_ownership_moves_admin_rights(contract: Contract<Poap>, alice: Address, bob: Address) {
+    deploy(&contract, alice);
+
+    contract
+        .sender(alice)
+        .transfer_ownership(bob)
+        .motsu_expect("owner should transfer ownership");
+
+    assert_eq!(contract.sender(alice).get_owner(), bob);
+    let err = contract
+        .sender(alice)
+        .create_event(String::from("ETHGlobal"), alice)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::OwnableUnauthorizedAccount(_)));
+    create_event(&contract, bob, bob);
+}
+
+#[motsu::test]
+fn transfer_ownership_reverts_for_non_owner(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(bob)
+        .transfer_ownership(bob)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::OwnableUnauthorizedAccount(_)));
+
+    let err = contract
+        .sender(alice)
+        .transfer_ownership(Address::ZERO)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::OwnableInvalidOwner(_)));
+}
+
+// ============ META-TRANSACTIONS ============
+
+fn forwarded(call: impl SolCall, signer: Address) -> Bytes {
+    let mut calldata = call.abi_encode();
+    calldata.extend_from_slice(signer.as_slice());
+    calldata.into()
+}
+
+#[motsu::test]
+fn set_trusted_forwarder_is_owner_only(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    forwarder: Address,
+) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(bob)
+        .set_trusted_forwarder(forwarder)
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::OwnableUnauthorizedAccount(_)));
+
+    contract
+        .sender(alice)
+        .set_trusted_forwarder(forwarder)
+        .motsu_expect("owner should set the forwarder");
+    assert_eq!(contract.sender(alice).get_trusted_forwarder(), forwarder);
+    assert!(contract.sender(alice).is_trusted_forwarder(forwarder));
+    assert!(!contract.sender(alice).is_trusted_forwarder(bob));
+    assert!(contract.emitted(&TrustedForwarderSet { forwarder }));
+}
+
+#[motsu::test]
+fn forwarded_mint_uses_signer(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+    forwarder: Address,
+) {
+    deploy(&contract, alice);
+    let event_id = create_event(&contract, alice, bob);
+    contract
+        .sender(alice)
+        .set_trusted_forwarder(forwarder)
+        .motsu_unwrap();
+
+    let call = mintTokenCall {
+        eventId: event_id,
+        to: dave,
+    };
+    let output = contract
+        .sender(forwarder)
+        .execute_forwarded(forwarded(call.clone(), bob))
+        .motsu_expect("forwarder should relay the organizer's mint");
+
+    let token_id = uint!(1_U256);
+    assert_eq!(output.to_vec(), token_id.abi_encode());
+    assert_eq!(
+        contract.sender(alice).owner_of(token_id).motsu_unwrap(),
+        dave
+    );
+
+    let err = contract
+        .sender(forwarder)
+        .execute_forwarded(forwarded(call, dave))
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::NotEventMinter(NotEventMinter { account, .. }) if account == dave
+    ));
+}
+
+#[motsu::test]
+fn forwarded_admin_call_checks_signer(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    forwarder: Address,
+) {
+    deploy(&contract, alice);
+    contract
+        .sender(alice)
+        .set_trusted_forwarder(forwarder)
+        .motsu_unwrap();
+    let call = createEventCall {
+        name: String::from("ETHGlobal"),
+        organizer: bob,
+    };
+
+    let err = contract
+        .sender(forwarder)
+        .execute_forwarded(forwarded(call.clone(), bob))
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::OwnableUnauthorizedAccount(OwnableUnauthorizedAccount { account })
+            if account == bob
+    ));
+
+    contract
+        .sender(forwarder)
+        .execute_forwarded(forwarded(call, alice))
+        .motsu_expect("forwarder should relay the owner's call");
+    assert_eq!(
+        contract.sender(alice).get_event_organizer(uint!(1_U256)),
+        bob
+    );
+}
+
+#[motsu::test]
+fn router_rejects_appended_sender(
+    proxy: Contract<ProxyMock>,
+    implementation: Contract<Poap>,
+    alice: Address,
+) {
+    deploy_proxy(&proxy, &implementation, alice);
+    let call = createEventCall {
+        name: String::from("ETHGlobal"),
+        organizer: alice,
+    };
+
+    // Plain ERC-2771 calldata does not reach `create_event`, which is why
+    // relayed calls are wrapped in `executeForwarded`.
+    proxy
+        .sender(alice)
+        .fallback(&forwarded(call.clone(), alice))
+        .motsu_unwrap_err();
+    call_proxy(&proxy, alice, call).motsu_expect("owner should create an event");
+}
+
+#[motsu::test]
+fn relayed_signer_only_applies_to_forwarder_calls(
+    contract: Contract<Poap>,
+    alice: Address,
+    forwarder: Address,
+    hook: Address,
+) {
+    deploy(&contract, alice);
+    contract
+        .sender(alice)
+        .set_trusted_forwarder(forwarder)
+        .motsu_unwrap();
+    // As seen by a hook called back while a call is relayed for the owner.
+    contract.sender(forwarder).forwarded_sender.set(alice);
+
+    let err = contract
+        .sender(hook)
+        .create_event(String::from("ETHGlobal"), hook)
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::OwnableUnauthorizedAccount(OwnableUnauthorizedAccount { account })
+            if account == hook
+    ));
+    assert_eq!(contract.sender(alice).get_last_event_id(), U256::ZERO);
+}
+
+#[motsu::test]
+fn execute_forwarded_rejects_untrusted_and_unsupported_calls(
+    contract: Contract<Poap>,
+    alice: Address,
+    forwarder: Address,
+) {
+    deploy(&contract, alice);
+    let call = || {
+        forwarded(
+            createEventCall {
+                name: String::from("ETHGlobal"),
+                organizer: alice,
+            },
+            alice,
+        )
+    };
+
+    let err = contract
+        .sender(forwarder)
+        .execute_forwarded(call())
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::UntrustedForwarder(UntrustedForwarder { forwarder: account }) if account == forwarder
+    ));
+
+    contract
+        .sender(alice)
+        .set_trusted_forwarder(forwarder)
+        .motsu_unwrap();
+    let mut calldata = fixed_bytes!("f2fde38b").to_vec();
+    calldata.extend_from_slice(alice.as_slice());
+    let err = contract
+        .sender(forwarder)
+        .execute_forwarded(calldata.into())
+        .motsu_unwrap_err();
+    assert!(matches!(
+        err,
+        PoapError::UnsupportedForwardedCall(UnsupportedForwardedCall { selector })
+            if selector == fixed_bytes!("f2fde38b")
+    ));
+
+    let err = contract
+        .sender(forwarder)
+        .execute_forwarded(alice.to_vec().into())
+        .motsu_unwrap_err();
+    assert!(matches!(err, PoapError::InvalidForwardedCall(_)));
+}
+
+// ============ UPGRADES ============
+
+#[motsu::test]
+fn upgrade_sets_implementation(
+    proxy: Contract<ProxyMock>,
+    implementation: Contract<Poap>,
+    next: Contract<Poap>,
+    alice: Address,
+) {
+    deploy_proxy(&proxy, &implementation, alice);
+
+    call_proxy(
+        &proxy,
+        alice,
+        upgradeToAndCallCall {
+            newImplementation: next.address(),
+            data: Vec::new().into(),
+        },
+    )
+    .motsu_expect("owner should upgrade");
+
+    assert_eq!(proxy_implementation(&proxy, alice), next.address());
+}
+
+#[motsu::test]
+fn upgrade_reverts_for_non_owner(
+    proxy: Contract<ProxyMock>,
+    implementation: Contract<Poap>,
+    next: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+) {
+    deploy_proxy(&proxy, &implementation, alice);
+
+    let err = call_proxy(
+        &proxy,
+        bob,
+        upgradeToAndCallCall {
+            newImplementation: next.address(),
+            data: Vec::new().into(),
+        },
+    )
+    .motsu_unwrap_err();
+
+    assert_eq!(
+        err,
+        OwnableUnauthorizedAccount { account: bob }.abi_encode()
+    );
+    assert_eq!(
+        proxy_implementation(&proxy, alice),
+        implementation.address()
+    );
+}
+
+#[motsu::test]
+fn upgrade_reverts_when_not_called_through_proxy(
+    contract: Contract<Poap>,
+    next: Contract<Poap>,
+    alice: Address,
+) {
+    deploy(&contract, alice);
+
+    let err = contract
+        .sender(alice)
+        .upgrade_to_and_call(next.address(), Vec::<u8>::new().into())
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, PoapError::UUPSUnauthorizedCallContext(_)));
+    assert_eq!(contract.sender(alice).get_implementation(), Address::ZERO);
+}
+
+#[motsu::test]
+fn proxiable_uuid_reverts_through_proxy(
+    proxy: Contract<ProxyMock>,
+    implementation: Contract<Poap>,
+    alice: Address,
+) {
+    deploy_proxy(&proxy, &implementation, alice);
+
+    assert_eq!(
+        implementation.sender(alice).proxiable_uuid().motsu_unwrap(),
+        IMPLEMENTATION_SLOT
+    );
+    let err = call_proxy(&proxy, alice, proxiableUUIDCall {}).motsu_unwrap_err();
+    assert_eq!(err, UUPSUnauthorizedCallContext {}.abi_encode());
+}
+
+#[motsu::test]
+fn upgrade_reverts_for_non_proxiable_implementation(
+    proxy: Contract<ProxyMock>,
+    other_proxy: Contract<ProxyMock>,
+    implementation: Contract<Poap>,
+    non_proxiable: Contract<NonReceiverMock>,
+    alice: Address,
+) {
+    deploy_proxy(&proxy, &implementation, alice);
+    deploy_proxy(&other_proxy, &implementation, alice);
+
+    for target in [non_proxiable.address(), other_proxy.address()] {
+        let err = call_proxy(
+            &proxy,
+            alice,
+            upgradeToAndCallCall {
+                newImplementation: target,
+                data: Vec::new().into(),
+            },
+        )
+        .motsu_unwrap_err();
+
+        assert_eq!(
+            err,
+            ERC1967InvalidImplementation {
+                implementation: target
+            }
+            .abi_encode()
+        );
+    }
+    assert_eq!(
+        proxy_implementation(&proxy, alice),
+        implementation.address()
+    );
+}
+
+#[motsu::test]
+fn storage_layout_is_compatible_with_v1(
+    proxy: Contract<ProxyMock>,
+    other_proxy: Contract<ProxyMock>,
+    implementation: Contract<Poap>,
+    layout: Contract<PoapLayoutV1>,
+    alice: Address,
+    bob: Address,
+) {
+    let event_id = uint!(1_U256);
+    let token_id = uint!(1_U256);
+    let setup = |proxy: &Contract<ProxyMock>| {
+        deploy_proxy(proxy, &implementation, alice);
+        call_proxy(
+            proxy,
+            alice,
+            createEventCall {
+                name: String::from("ETHGlobal"),
+                organizer: alice,
+            },
+        )
+        .motsu_expect("owner should create an event");
+        call_proxy(
+            proxy,
+            alice,
+            mintTokenCall {
+                eventId: event_id,
+                to: bob,
+            },
+        )
+        .motsu_expect("organizer should mint a badge");
+    };
+    let check = |proxy: &Contract<ProxyMock>, attendee| {
+        let data = checkLayoutCall {
+            owner: alice,
+            eventId: event_id,
+            attendee,
+            tokenId: token_id,
+        }
+        .abi_encode();
+        call_proxy(
+            proxy,
+            alice,
+            upgradeToAndCallCall {
+                newImplementation: layout.address(),
+                data: data.into(),
+            },
+        )
+    };
+
+    // A passing check leaves the proxy pointing at the layout, so each check
+    // runs on its own proxy.
+    setup(&proxy);
+    setup(&other_proxy);
+
+    let err = check(&proxy, alice).motsu_unwrap_err();
+    assert_eq!(err, FailedCall {}.abi_encode());
+
+    check(&other_proxy, bob).motsu_expect("V1 layout should read the current state");
+}
+
+// ============ ATTENDANCE ============
+
+#[motsu::test]
+fn attendance_queries_track_held_event_badges(
+    contract: Contract<Poap>,
+    alice: Address,
+    bob: Address,
+    dave: Address,
+) {
+    deploy(&contract, alice);
+    let first_event = create_event(&contract, alice, bob);
+    let second_event = create_event(&contract, alice, bob);
+    let first_token = mint(&contract, bob, first_event, dave);
+    mint(&contract, bob, second_event, dave);
+    let achievement_id = contract
+        .sender(alice)
+        .create_achievement(String::from("A"), vec![first_event], uint!(1_U256))
+        .motsu_unwrap();
+    contract
+        .sender(dave)
+        .claim_achievement(achievement_id)
+        .motsu_unwrap();
+
+    assert!(contract.sender(alice).has_attended(first_event, dave));
+    assert!(!contract.sender(alice).has_attended(first_event, bob));
+    assert!(contract
+        .sender(alice)
+        .has_attended_any(vec![uint!(99_U256), second_event], dave));
+    assert!(!contract
+        .sender(alice)
+        .has_attended_any(vec![first_event, second_event], bob));
+    assert!(!contract.sender(alice).has_attended_any(vec![], dave));
+    assert_eq!(contract.sender(alice).attended_count(dave), uint!(2_U256));
+    assert_eq!(contract.sender(alice).attended_count(bob), U256::ZERO);
+
+    contract
+        .sender(bob)
+        .revoke_badge(first_token)
+        .motsu_unwrap();
+
+    assert!(!contract.sender(alice).has_attended(first_event, dave));
+    assert_eq!(contract.sender(alice).attended_count(dave), uint!(1_U256));
+}
+
+// ============ ERC-165 ============
+
+#[motsu::test]
+fn supports_interface(contract: Contract<Poap>, alice: Address) {
+    deploy(&contract, alice);
+
+    let erc721_id = <Erc721 as IErc721>::interface_id();
+    let metadata_id = <Poap as IErc721Metadata>::interface_id();
+    let enumerable_id = <Poap as IErc721Enumerable>::interface_id();
+    let attendance_id = <Poap as IPoapAttendance>::interface_id();
+    let erc165_id = <Poap as IErc165>::interface_id();
+
+    for interface_id in [
+        erc721_id,
+        metadata_id,
+        enumerable_id,
+        attendance_id,
+        erc165_id,
+    ] {
+        assert!(contract.sender(alice).supports_interface(interface_id));
+    }
+    assert!(!contract
+        .sender(alice)
+        .supports_interface(fixed_bytes!("12345678")));
+}
@@ -3,34 +3,163 @@ use alloy::sol;
 
 sol!(
     #[sol(rpc)]
-   contract Erc721 {
-        constructor(string memory name, string memory symbol, string memory base_uri);
+   contract Poap {
+        function initialize(string memory name, string memory symbol, string memory base_uri, address owner) external;
+
+        // Event management
+        function createEvent(string memory name, address organizer) external returns (uint256);
+        function addEventMinter(uint256 eventId, address minter) external;
+        function setEventMintHook(uint256 eventId, address hook, bool revertOnFailure) external;
+        function addEventRole(uint256 eventId, string memory name, uint256 supplyCap) external returns (uint256);
+        function setRoleMinter(uint256 eventId, uint256 roleId, address minter, bool allowed) external;
+        function createSessionEvent(string memory name, address organizer, uint256 sessionThreshold, bool autoMint) external returns (uint256);
+        function addEventSession(uint256 eventId, string memory name) external returns (uint256);
+        function checkIn(uint256 eventId, uint256 sessionId, address attendee) external returns (uint256);
+        function claimEventBadge(uint256 eventId) external returns (uint256);
+        function setEventAllowlistRoot(uint256 eventId, bytes32 root) external;
+        function claimWithProof(uint256 eventId, bytes32[] memory proof) external returns (uint256);
+        function mintToken(uint256 eventId, address to) external returns (uint256);
+        function mintTokenWithRole(uint256 eventId, address to, uint256 roleId) external returns (uint256);
+        function mintTokenSafe(uint256 eventId, address to, bytes calldata data) external returns (uint256);
+        function revokeBadge(uint256 tokenId) external;
+
+        // Achievements
+        function createAchievement(string memory name, uint256[] memory eventIds, uint256 threshold) external returns (uint256);
+        function setAchievementActive(uint256 achievementId, bool active) external;
+        function claimAchievement(uint256 achievementId) external returns (uint256);
+
+        // Soulbound overrides
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+        function safeTransferFromWithData(address from, address to, uint256 tokenId, bytes calldata data) external;
+        function transferFrom(address from, address to, uint256 tokenId) external;
         function approve(address to, uint256 tokenId) external;
+        function setApprovalForAll(address operator, bool approved) external;
+
+        // ERC721 reads
         #[derive(Debug)]
         function balanceOf(address owner) external view returns (uint256 balance);
         #[derive(Debug)]
+        function ownerOf(uint256 tokenId) external view returns (address ownerOf);
+        #[derive(Debug)]
         function getApproved(uint256 tokenId) external view returns (address approved);
         #[derive(Debug)]
         function isApprovedForAll(address owner, address operator) external view returns (bool approved);
         #[derive(Debug)]
-        function ownerOf(uint256 tokenId) external view returns (address ownerOf);
-        function safeTransferFrom(address from, address to, uint256 tokenId) external;
-        function safeTransferFrom(address from, address to, uint256 tokenId, bytes calldata data) external;
-        function setApprovalForAll(address operator, bool approved) external;
-        function totalSupply() external view returns (uint256 totalSupply);
-        function transferFrom(address from, address to, uint256 tokenId) external;
-        function mint(address to) external;
+        function name() external view returns (string memory name);
+        #[derive(Debug)]
+        function symbol() external view returns (string memory symbol);
+        #[derive(Debug)]
+        function tokenURI(uint256 tokenId) external view returns (string memory tokenURI);
+
+        // Admin
+        function transferOwnership(address newOwner) external;
+        #[derive(Debug)]
+        function getOwner() external view returns (address owner);
+
+        // Meta-transactions (ERC-2771)
+        function setTrustedForwarder(address forwarder) external;
+        #[derive(Debug)]
+        function getTrustedForwarder() external view returns (address forwarder);
+        #[derive(Debug)]
+        function isTrustedForwarder(address forwarder) external view returns (bool trusted);
+        function executeForwarded(bytes calldata data) external returns (bytes memory);
+
+        // Upgrades
+        function upgradeToAndCall(address newImplementation, bytes calldata data) external payable;
+        #[derive(Debug)]
+        function proxiableUUID() external view returns (bytes32 slot);
+        #[derive(Debug)]
+        function getImplementation() external view returns (address implementation);
+
+        // Event views
+        #[derive(Debug)]
+        function getEventName(uint256 eventId) external view returns (string memory name);
+        #[derive(Debug)]
+        function isEventMinter(uint256 eventId, address minter) external view returns (bool isMinter);
+        #[derive(Debug)]
+        function getEventOrganizer(uint256 eventId) external view returns (address organizer);
+        #[derive(Debug)]
+        function isEventActive(uint256 eventId) external view returns (bool active);
+        #[derive(Debug)]
+        function getEventRoleCount(uint256 eventId) external view returns (uint256 count);
+        #[derive(Debug)]
+        function getEventRole(uint256 eventId, uint256 roleId) external view returns (uint256, string memory, uint256, uint256);
+        #[derive(Debug)]
+        function isRoleMinter(uint256 eventId, uint256 roleId, address minter) external view returns (bool isMinter);
+        #[derive(Debug)]
+        function getTokenRole(uint256 tokenId) external view returns (uint256 roleId);
+        #[derive(Debug)]
+        function getEventSessionThreshold(uint256 eventId) external view returns (uint256 threshold);
+        #[derive(Debug)]
+        function isEventAutoMint(uint256 eventId) external view returns (bool autoMint);
+        #[derive(Debug)]
+        function getEventSessionCount(uint256 eventId) external view returns (uint256 count);
+        #[derive(Debug)]
+        function getEventSessionName(uint256 eventId, uint256 sessionId) external view returns (string memory name);
+        #[derive(Debug)]
+        function hasCheckedIn(uint256 eventId, uint256 sessionId, address attendee) external view returns (bool checkedIn);
+        #[derive(Debug)]
+        function getAttendedSessionCount(uint256 eventId, address attendee) external view returns (uint256 attended);
+        #[derive(Debug)]
+        function getEventMintHook(uint256 eventId) external view returns (address hook);
+        #[derive(Debug)]
+        function isEventMintHookRequired(uint256 eventId) external view returns (bool required);
+        #[derive(Debug)]
+        function getEventAllowlistRoot(uint256 eventId) external view returns (bytes32 root);
+        #[derive(Debug)]
+        function getLastTokenId() external view returns (uint256 tokenId);
+        #[derive(Debug)]
+        function getLastEventId() external view returns (uint256 eventId);
+        #[derive(Debug)]
+        function getTokenEvent(uint256 tokenId) external view returns (uint256 eventId);
+        #[derive(Debug)]
+        function getTokenInfo(uint256 tokenId) external view returns (uint256, uint256, uint256, address);
+        #[derive(Debug)]
+        function getEvent(uint256 eventId) external view returns (uint256, string memory, address, bool, uint256);
+        #[derive(Debug)]
+        function getEvents(uint256 offset, uint256 limit) external view returns ((uint256, string, address, bool, uint256)[] memory events);
+        #[derive(Debug)]
+        function getOrganizerEventCount(address organizer) external view returns (uint256 count);
+        #[derive(Debug)]
+        function getEventsByOrganizer(address organizer, uint256 offset, uint256 limit) external view returns ((uint256, string, address, bool, uint256)[] memory events);
+        #[derive(Debug)]
+        function getEventAttendeeCount(uint256 eventId) external view returns (uint256 count);
+        function getEventAttendeeListLength(uint256 eventId) external view returns (uint256 length);
+        #[derive(Debug)]
+        function getEventAttendees(uint256 eventId, uint256 offset, uint256 limit) external view returns (address[] memory attendees);
+        #[derive(Debug)]
+        function getHolderBadges(address owner, uint256 offset, uint256 limit) external view returns ((uint256, uint256, string)[] memory badges);
 
-        // ERC721Metadata functions
+        // Attendance (IPoapAttendance)
         #[derive(Debug)]
-        function name() external view returns (string memory);
+        function hasAttended(uint256 eventId, address account) external view returns (bool attended);
         #[derive(Debug)]
-        function symbol() external view returns (string memory);
+        function hasAttendedAny(uint256[] memory eventIds, address account) external view returns (bool attended);
         #[derive(Debug)]
-        function tokenURI(uint256 tokenId) external view returns (string memory);
+        function attendedCount(address account) external view returns (uint256 count);
+
+        // Achievement views
+        #[derive(Debug)]
+        function getLastAchievementId() external view returns (uint256 achievementId);
+        #[derive(Debug)]
+        function getAchievementName(uint256 achievementId) external view returns (string memory name);
+        #[derive(Debug)]
+        function getAchievementEvents(uint256 achievementId) external view returns (uint256[] memory eventIds);
+        #[derive(Debug)]
+        function getAchievementThreshold(uint256 achievementId) external view returns (uint256 threshold);
+        #[derive(Debug)]
+        function isAchievementActive(uint256 achievementId) external view returns (bool active);
+        #[derive(Debug)]
+        function hasClaimedAchievement(uint256 achievementId, address account) external view returns (bool claimed);
+        #[derive(Debug)]
+        function getAchievementProgress(uint256 achievementId, address account) external view returns (uint256 attended);
+        #[derive(Debug)]
+        function isAchievementToken(uint256 tokenId) external view returns (bool isAchievement);
 
         // ERC721Enumerable functions
         #[derive(Debug)]
+        function totalSupply() external view returns (uint256 totalSupply);
+        #[derive(Debug)]
         function tokenOfOwnerByIndex(address owner, uint256 index) external view returns (uint256 tokenId);
         #[derive(Debug)]
         function tokenByIndex(uint256 index) external view returns (uint256 tokenId);
@@ -40,6 +169,40 @@ sol!(
         error Error(string message);
         error Panic(uint256 code);
 
+        error UnauthorizedAction();
+        error EventNotFound(uint256 eventId);
+        error EventInactive(uint256 eventId);
+        error AlreadyAttended(uint256 eventId, address attendee);
+        error NotEventMinter(uint256 eventId, address account);
+        error AchievementNotFound(uint256 achievementId);
+        error AchievementInactive(uint256 achievementId);
+        error AchievementAlreadyClaimed(uint256 achievementId, address account);
+        error AchievementNotEarned(uint256 achievementId, address account, uint256 attended, uint256 threshold);
+        error InvalidAchievementThreshold(uint256 threshold, uint256 eventCount);
+        error DuplicateAchievementEvent(uint256 eventId);
+        error AlreadyInitialized();
+        error ERC1967InvalidImplementation(address implementation);
+        error UUPSUnsupportedProxiableUUID(bytes32 slot);
+        error UUPSUnauthorizedCallContext();
+        error FailedCall();
+        error UntrustedForwarder(address forwarder);
+        error UnsupportedForwardedCall(bytes4 selector);
+        error InvalidForwardedCall();
+        error NotEventOrganizer(uint256 eventId, address account);
+        error MintHookReverted(uint256 eventId, address hook);
+        error RoleNotFound(uint256 eventId, uint256 roleId);
+        error NotRoleMinter(uint256 eventId, uint256 roleId, address account);
+        error RoleSupplyExceeded(uint256 eventId, uint256 roleId, uint256 supplyCap);
+        error InvalidSessionThreshold(uint256 threshold);
+        error NotSessionEvent(uint256 eventId);
+        error SessionNotFound(uint256 eventId, uint256 sessionId);
+        error AlreadyCheckedIn(uint256 eventId, uint256 sessionId, address attendee);
+        error SessionThresholdNotMet(uint256 eventId, address attendee, uint256 attended, uint256 threshold);
+        error InvalidAllowlistProof(uint256 eventId, address account);
+
+        error OwnableUnauthorizedAccount(address account);
+        error OwnableInvalidOwner(address owner);
+
         error InvalidReceiverWithReason(string message);
         error ERC721IncorrectOwner(address sender, uint256 tokenId, address owner);
         error ERC721InsufficientApproval(address operator, uint256 tokenId);
@@ -55,8 +218,32 @@ sol!(
         #[derive(Debug, PartialEq)]
         event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
         #[derive(Debug, PartialEq)]
-        event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+        event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+        #[derive(Debug, PartialEq)]
+        event EventCreated(uint256 indexed eventId, address indexed organizer, string name);
+        #[derive(Debug, PartialEq)]
+        event EventMinterAdded(uint256 indexed eventId, address indexed minter);
+        #[derive(Debug, PartialEq)]
+        event BadgeMinted(uint256 indexed eventId, uint256 indexed tokenId, address indexed attendee);
+        #[derive(Debug, PartialEq)]
+        event Upgraded(address indexed implementation);
+        #[derive(Debug, PartialEq)]
+        event TrustedForwarderSet(address indexed forwarder);
+        #[derive(Debug, PartialEq)]
+        event EventMintHookSet(uint256 indexed eventId, address indexed hook, bool revertOnFailure);
+        #[derive(Debug, PartialEq)]
+        event MintHookFailed(uint256 indexed eventId, uint256 indexed tokenId, address indexed hook);
+        #[derive(Debug, PartialEq)]
+        event EventRoleAdded(uint256 indexed eventId, uint256 indexed roleId, string name, uint256 supplyCap);
+        #[derive(Debug, PartialEq)]
+        event RoleMinterSet(uint256 indexed eventId, uint256 indexed roleId, address indexed minter, bool allowed);
+        #[derive(Debug, PartialEq)]
+        event SessionAdded(uint256 indexed eventId, uint256 indexed sessionId, string name);
+        #[derive(Debug, PartialEq)]
+        event SessionCheckedIn(uint256 indexed eventId, uint256 indexed sessionId, address indexed attendee);
+        #[derive(Debug, PartialEq)]
+        event AchievementCreated(uint256 indexed achievementId, string name, uint256 threshold);
         #[derive(Debug, PartialEq)]
-        event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+        event EventAllowlistRootSet(uint256 indexed eventId, bytes32 root);
    }
 );
@@ -1 +1,3 @@
+pub mod proxy;
+pub mod read_back;
 pub mod receiver;
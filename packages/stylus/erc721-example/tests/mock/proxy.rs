@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+#![cfg(feature = "e2e")]
+use alloy::{
+    contract::RawCallBuilder,
+    hex,
+    primitives::{Address, Bytes},
+    sol,
+    sol_types::SolConstructor,
+};
+use e2e::Wallet;
+
+/// Creation code of the hand-assembled ERC-1967 proxy, the same file
+/// `scripts/deploy_proxy.ts` deploys; the script has the listing.
+const BYTECODE: &str = include_str!("../../../scripts/erc1967_proxy.hex");
+
+sol! {
+    #[allow(missing_docs)]
+    contract Erc1967ProxyMock {
+        #[derive(Debug, PartialEq)]
+        event Upgraded(address indexed implementation);
+
+        constructor(address implementation, bytes memory data);
+    }
+}
+
+/// Deploys a proxy in front of `implementation` that delegate-calls `data`,
+/// usually an encoded `initialize` call, in its deployment transaction.
+pub async fn deploy(
+    wallet: &Wallet,
+    implementation: Address,
+    data: Bytes,
+) -> eyre::Result<Address> {
+    let mut code = hex::decode(BYTECODE.trim())?;
+    code.extend(
+        Erc1967ProxyMock::constructorCall {
+            implementation,
+            data,
+        }
+        .abi_encode(),
+    );
+    let address = RawCallBuilder::new_raw_deploy(wallet, code.into())
+        .deploy()
+        .await?;
+    Ok(address)
+}
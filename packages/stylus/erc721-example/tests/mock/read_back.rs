@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+#![cfg(feature = "e2e")]
+use alloy::{primitives::Address, sol};
+use e2e::Wallet;
+
+sol! {
+    #[allow(missing_docs)]
+    // Hand-assembled. Both callbacks query the calling `Poap` and revert
+    // unless it already recorded the badge:
+    //
+    // - `onPoapMinted(eventId, tokenId, recipient)` requires
+    //   `hasAttended(eventId, recipient)`, then stops.
+    // - `onERC721Received(operator, from, tokenId, data)` requires a non-zero
+    //   `getTokenEvent(tokenId)`, then returns its own selector.
+    //
+    // Any other call reverts.
+    #[sol(rpc, bytecode="60798060095f395ff35f3560e01c8063fd87a25514601c5763150b7a02146046575b5f5ffd5b63a0ea32c260e01b5f52602060046004376020604460243760205f60445f335afa5f511615601857005b633092dc0960e01b5f526020604460043760205f60245f335afa156018575f511560185763150b7a0260e01b5f5260205ff3")]
+    contract ReadBackMock {
+        function onPoapMinted(uint256 eventId, uint256 tokenId, address recipient) external;
+        function onERC721Received(address operator, address from, uint256 tokenId, bytes calldata data) external returns (bytes4);
+    }
+}
+
+/// Deploys a mint hook and ERC-721 receiver that reads the badge it is
+/// notified of back from `Poap`.
+pub async fn deploy(wallet: &Wallet) -> eyre::Result<Address> {
+    let contract = ReadBackMock::deploy(wallet).await?;
+    Ok(*contract.address())
+}
@@ -0,0 +1,868 @@
+#![cfg(feature = "e2e")]
+
+use abi::Poap;
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{fixed_bytes, uint, Address, Bytes, U256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol_types::{SolCall, SolError},
+};
+use e2e::{receipt, send, watch, Account, EventExt, Revert};
+use mock::{proxy, read_back, receiver, receiver::ERC721ReceiverMock};
+
+mod abi;
+mod mock;
+
+const NAME: &str = "POAP";
+const SYMBOL: &str = "POAP";
+const BASE_URI: &str = "https://ipfs.io/ipfs/";
+const EVENT_NAME: &str = "ETHGlobal";
+
+trait EncodeAsStr {
+    fn encode_as_str(&self) -> String;
+}
+
+impl<T: SolError> EncodeAsStr for T {
+    fn encode_as_str(&self) -> String {
+        let expected_error = self.abi_encode();
+        String::from_utf8_lossy(&expected_error).to_string()
+    }
+}
+
+/// Deploys a `Poap` implementation behind an ERC-1967 proxy that initializes
+/// it in the same transaction, and returns the proxy address.
+async fn deploy(owner: &Account) -> eyre::Result<Address> {
+    let implementation = owner.as_deployer().deploy().await?.contract_address;
+    let data = Poap::initializeCall {
+        name: NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        base_uri: BASE_URI.to_string(),
+        owner: owner.address(),
+    }
+    .abi_encode();
+    proxy::deploy(&owner.wallet, implementation, data.into()).await
+}
+
+/// Creates an event organized by `organizer` and returns its id.
+async fn create_event(
+    contract: &Poap::PoapInstance<&e2e::Wallet>,
+    organizer: Address,
+) -> eyre::Result<U256> {
+    watch!(contract.createEvent(EVENT_NAME.to_string(), organizer))?;
+    let Poap::getLastEventIdReturn { eventId } = contract.getLastEventId().call().await?;
+    Ok(eventId)
+}
+
+// ============================================================================
+// Integration Tests: Deployment
+// ============================================================================
+
+#[e2e::test]
+async fn initializes(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+
+    let Poap::nameReturn { name } = contract.name().call().await?;
+    let Poap::symbolReturn { symbol } = contract.symbol().call().await?;
+    let Poap::getOwnerReturn { owner } = contract.getOwner().call().await?;
+    let Poap::getLastEventIdReturn { eventId } = contract.getLastEventId().call().await?;
+    let Poap::getLastTokenIdReturn { tokenId } = contract.getLastTokenId().call().await?;
+
+    assert_eq!(NAME, name);
+    assert_eq!(SYMBOL, symbol);
+    assert_eq!(alice.address(), owner);
+    assert_eq!(U256::ZERO, eventId);
+    assert_eq!(U256::ZERO, tokenId);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_initialized_twice(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &bob.wallet);
+
+    let err = send!(contract.initialize(
+        NAME.to_string(),
+        SYMBOL.to_string(),
+        BASE_URI.to_string(),
+        bob.address()
+    ))
+    .expect_err("should not initialize twice");
+
+    assert!(err.reverted_with(Poap::AlreadyInitialized {}));
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: Meta-transactions
+// ============================================================================
+
+#[e2e::test]
+async fn relays_only_from_trusted_forwarder(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let contract_bob = Poap::new(contract_addr, &bob.wallet);
+    let bob_addr = bob.address();
+
+    let calldata: Bytes = [
+        Poap::createEventCall {
+            name: EVENT_NAME.to_string(),
+            organizer: bob_addr,
+        }
+        .abi_encode(),
+        alice.address().to_vec(),
+    ]
+    .concat()
+    .into();
+
+    let err = send!(contract_bob.executeForwarded(calldata.clone()))
+        .expect_err("should not relay from an untrusted forwarder");
+    assert!(err.reverted_with(Poap::UntrustedForwarder {
+        forwarder: bob_addr
+    }));
+
+    let receipt = receipt!(contract.setTrustedForwarder(bob_addr))?;
+    assert!(receipt.emits(Poap::TrustedForwarderSet {
+        forwarder: bob_addr
+    }));
+
+    watch!(contract_bob.executeForwarded(calldata))?;
+    let Poap::getEventOrganizerReturn { organizer } =
+        contract.getEventOrganizer(uint!(1_U256)).call().await?;
+    assert_eq!(bob_addr, organizer);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_sender_is_appended_to_calldata(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+
+    // Plain ERC-2771 calldata: the router rejects the trailing sender, which
+    // is why relayed calls go through `executeForwarded`.
+    let calldata = [
+        Poap::createEventCall {
+            name: EVENT_NAME.to_string(),
+            organizer: alice.address(),
+        }
+        .abi_encode(),
+        alice.address().to_vec(),
+    ]
+    .concat();
+    let tx = TransactionRequest::default()
+        .with_to(contract_addr)
+        .with_input(calldata);
+
+    let result = alice.wallet.send_transaction(tx).await;
+    assert!(result.is_err(), "router should reject trailing calldata");
+
+    let Poap::getLastEventIdReturn { eventId } = contract.getLastEventId().call().await?;
+    assert_eq!(U256::ZERO, eventId);
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: Upgrades
+// ============================================================================
+
+#[e2e::test]
+async fn upgrades_to_new_implementation(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let next_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+
+    let event_id = create_event(&contract, alice.address()).await?;
+    watch!(contract.mintToken(event_id, bob.address()))?;
+    let Poap::getImplementationReturn { implementation } =
+        contract.getImplementation().call().await?;
+    assert_ne!(next_addr, implementation);
+
+    let receipt = receipt!(contract.upgradeToAndCall(next_addr, Bytes::new()))?;
+
+    assert!(receipt.emits(Poap::Upgraded {
+        implementation: next_addr,
+    }));
+    let Poap::getImplementationReturn { implementation } =
+        contract.getImplementation().call().await?;
+    assert_eq!(next_addr, implementation);
+
+    // State lives in the proxy and survives the upgrade.
+    let Poap::getOwnerReturn { owner } = contract.getOwner().call().await?;
+    let Poap::ownerOfReturn { ownerOf } = contract.ownerOf(uint!(1_U256)).call().await?;
+    let Poap::getEventNameReturn { name } = contract.getEventName(event_id).call().await?;
+    assert_eq!(alice.address(), owner);
+    assert_eq!(bob.address(), ownerOf);
+    assert_eq!(EVENT_NAME, name);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_non_owner_upgrades(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let next_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Poap::new(contract_addr, &bob.wallet);
+
+    let err = send!(contract.upgradeToAndCall(next_addr, Bytes::new()))
+        .expect_err("should not upgrade as non-owner");
+
+    assert!(err.reverted_with(Poap::OwnableUnauthorizedAccount {
+        account: bob.address()
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_upgrading_implementation_directly(alice: Account) -> eyre::Result<()> {
+    let implementation_addr = alice.as_deployer().deploy().await?.contract_address;
+    let next_addr = alice.as_deployer().deploy().await?.contract_address;
+    let implementation = Poap::new(implementation_addr, &alice.wallet);
+
+    let err = send!(implementation.upgradeToAndCall(next_addr, Bytes::new()))
+        .expect_err("should only upgrade through the proxy");
+
+    assert!(err.reverted_with(Poap::UUPSUnauthorizedCallContext {}));
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: Event Management
+// ============================================================================
+
+#[e2e::test]
+async fn creates_event(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let event_id = create_event(&contract, bob_addr).await?;
+    assert_eq!(uint!(1_U256), event_id);
+
+    let Poap::getEventNameReturn { name } = contract.getEventName(event_id).call().await?;
+    let Poap::getEventOrganizerReturn { organizer } =
+        contract.getEventOrganizer(event_id).call().await?;
+    let Poap::isEventActiveReturn { active } = contract.isEventActive(event_id).call().await?;
+    let Poap::isEventMinterReturn { isMinter } =
+        contract.isEventMinter(event_id, bob_addr).call().await?;
+
+    assert_eq!(EVENT_NAME, name);
+    assert_eq!(bob_addr, organizer);
+    assert!(active);
+    assert!(isMinter);
+
+    let event = contract.getEvent(event_id).call().await?;
+    assert_eq!(event_id, event._0);
+    assert_eq!(EVENT_NAME, event._1);
+    assert_eq!(bob_addr, event._2);
+    assert!(event._3);
+    assert_eq!(U256::ZERO, event._4);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_non_owner_creates_event(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &bob.wallet);
+    let bob_addr = bob.address();
+
+    let err = send!(contract.createEvent(EVENT_NAME.to_string(), bob_addr))
+        .expect_err("should not create event as non-owner");
+
+    assert!(err.reverted_with(Poap::OwnableUnauthorizedAccount { account: bob_addr }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn adds_event_minter(alice: Account, bob: Account, charlie: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let charlie_addr = charlie.address();
+
+    let event_id = create_event(&contract, bob.address()).await?;
+    watch!(contract.addEventMinter(event_id, charlie_addr))?;
+
+    let Poap::isEventMinterReturn { isMinter } = contract
+        .isEventMinter(event_id, charlie_addr)
+        .call()
+        .await?;
+    assert!(isMinter);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_adding_minter_to_nonexistent_event(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let event_id = uint!(1_U256);
+
+    let err = send!(contract.addEventMinter(event_id, bob.address()))
+        .expect_err("should not add minter to nonexistent event");
+
+    assert!(err.reverted_with(Poap::EventNotFound { eventId: event_id }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_non_owner_adds_minter(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract_alice = Poap::new(contract_addr, &alice.wallet);
+    let contract_bob = Poap::new(contract_addr, &bob.wallet);
+    let bob_addr = bob.address();
+
+    let event_id = create_event(&contract_alice, bob_addr).await?;
+
+    let err = send!(contract_bob.addEventMinter(event_id, bob_addr))
+        .expect_err("should not add minter as non-owner");
+
+    assert!(err.reverted_with(Poap::OwnableUnauthorizedAccount { account: bob_addr }));
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: Minting
+// ============================================================================
+
+#[e2e::test]
+async fn organizer_mints_badge(alice: Account, bob: Account, charlie: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract_alice = Poap::new(contract_addr, &alice.wallet);
+    let contract_bob = Poap::new(contract_addr, &bob.wallet);
+    let charlie_addr = charlie.address();
+
+    let event_id = create_event(&contract_alice, bob.address()).await?;
+    let receipt = receipt!(contract_bob.mintToken(event_id, charlie_addr))?;
+
+    let token_id = uint!(1_U256);
+    assert!(receipt.emits(Poap::Transfer {
+        from: Address::ZERO,
+        to: charlie_addr,
+        tokenId: token_id,
+    }));
+    assert!(receipt.emits(Poap::BadgeMinted {
+        eventId: event_id,
+        tokenId: token_id,
+        attendee: charlie_addr,
+    }));
+
+    let Poap::ownerOfReturn { ownerOf } = contract_alice.ownerOf(token_id).call().await?;
+    let Poap::balanceOfReturn { balance } = contract_alice.balanceOf(charlie_addr).call().await?;
+    let Poap::getTokenEventReturn { eventId } =
+        contract_alice.getTokenEvent(token_id).call().await?;
+    let Poap::getEventAttendeeCountReturn { count } = contract_alice
+        .getEventAttendeeCount(event_id)
+        .call()
+        .await?;
+
+    assert_eq!(charlie_addr, ownerOf);
+    assert_eq!(uint!(1_U256), balance);
+    assert_eq!(event_id, eventId);
+    assert_eq!(uint!(1_U256), count);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn owner_mints_badge(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let event_id = create_event(&contract, bob_addr).await?;
+    watch!(contract.mintToken(event_id, bob_addr))?;
+
+    let Poap::balanceOfReturn { balance } = contract.balanceOf(bob_addr).call().await?;
+    assert_eq!(uint!(1_U256), balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_non_minter_mints(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract_alice = Poap::new(contract_addr, &alice.wallet);
+    let contract_bob = Poap::new(contract_addr, &bob.wallet);
+    let bob_addr = bob.address();
+
+    let event_id = create_event(&contract_alice, alice.address()).await?;
+
+    let err = send!(contract_bob.mintToken(event_id, bob_addr))
+        .expect_err("should not mint as non-minter");
+
+    assert!(err.reverted_with(Poap::NotEventMinter {
+        eventId: event_id,
+        account: bob_addr,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_minting_twice_to_attendee(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let event_id = create_event(&contract, alice.address()).await?;
+    watch!(contract.mintToken(event_id, bob_addr))?;
+
+    let err = send!(contract.mintToken(event_id, bob_addr))
+        .expect_err("should not mint twice to the same attendee");
+
+    assert!(err.reverted_with(Poap::AlreadyAttended {
+        eventId: event_id,
+        attendee: bob_addr,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_minting_for_nonexistent_event(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let event_id = uint!(1_U256);
+
+    let err = send!(contract.mintToken(event_id, alice.address()))
+        .expect_err("should not mint for nonexistent event");
+
+    assert!(err.reverted_with(Poap::EventNotFound { eventId: event_id }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_minting_to_invalid_receiver(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+
+    let event_id = create_event(&contract, alice.address()).await?;
+
+    let err = send!(contract.mintToken(event_id, Address::ZERO))
+        .expect_err("should not mint to the zero address");
+
+    assert!(err.reverted_with(Poap::ERC721InvalidReceiver {
+        receiver: Address::ZERO
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn safe_mints_to_receiver_contract(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let alice_addr = alice.address();
+
+    let receiver_address =
+        receiver::deploy(&alice.wallet, ERC721ReceiverMock::RevertType::None).await?;
+    let event_id = create_event(&contract, alice_addr).await?;
+    let data: Bytes = fixed_bytes!("deadbeef").into();
+
+    let receipt = receipt!(contract.mintTokenSafe(event_id, receiver_address, data.clone()))?;
+
+    let token_id = uint!(1_U256);
+    assert!(receipt.emits(ERC721ReceiverMock::Received {
+        operator: alice_addr,
+        from: Address::ZERO,
+        tokenId: token_id,
+        data,
+    }));
+
+    let Poap::ownerOfReturn { ownerOf } = contract.ownerOf(token_id).call().await?;
+    assert_eq!(receiver_address, ownerOf);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn safe_mint_receiver_reads_minted_badge(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let event_id = create_event(&contract, alice.address()).await?;
+
+    // The receiver only accepts the badge if `getTokenEvent` already returns
+    // its event.
+    let receiver_address = read_back::deploy(&alice.wallet).await?;
+    watch!(contract.mintTokenSafe(event_id, receiver_address, Bytes::new()))?;
+
+    let token_id = uint!(1_U256);
+    let Poap::ownerOfReturn { ownerOf } = contract.ownerOf(token_id).call().await?;
+    assert_eq!(receiver_address, ownerOf);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_safe_minting_to_reverting_receiver(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+
+    let receiver_address = receiver::deploy(
+        &alice.wallet,
+        ERC721ReceiverMock::RevertType::RevertWithMessage,
+    )
+    .await?;
+    let event_id = create_event(&contract, alice.address()).await?;
+
+    let err = send!(contract.mintTokenSafe(event_id, receiver_address, Bytes::new()))
+        .expect_err("should not mint when receiver reverts");
+
+    let message = Poap::Error {
+        message: "ERC721ReceiverMock: reverting".to_string(),
+    }
+    .encode_as_str();
+    assert!(err.reverted_with(Poap::InvalidReceiverWithReason { message }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_safe_minting_to_silently_reverting_receiver(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+
+    let receiver_address = receiver::deploy(
+        &alice.wallet,
+        ERC721ReceiverMock::RevertType::RevertWithoutMessage,
+    )
+    .await?;
+    let event_id = create_event(&contract, alice.address()).await?;
+
+    let err = send!(contract.mintTokenSafe(event_id, receiver_address, Bytes::new()))
+        .expect_err("should not mint when receiver reverts");
+
+    assert!(err.reverted_with(Poap::ERC721InvalidReceiver {
+        receiver: receiver_address
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn mint_hook_failure_follows_event_setting(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+    let event_id = create_event(&contract, alice.address()).await?;
+
+    // `Poap` has no `onPoapMinted`, so using it as its own hook always fails.
+    watch!(contract.setEventMintHook(event_id, contract_addr, true))?;
+    let err = send!(contract.mintToken(event_id, bob_addr)).expect_err("hook should revert mint");
+    assert!(err.reverted_with(Poap::MintHookReverted {
+        eventId: event_id,
+        hook: contract_addr,
+    }));
+
+    watch!(contract.setEventMintHook(event_id, contract_addr, false))?;
+    let receipt = receipt!(contract.mintToken(event_id, bob_addr))?;
+    assert!(receipt.emits(Poap::MintHookFailed {
+        eventId: event_id,
+        tokenId: uint!(1_U256),
+        hook: contract_addr,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn mint_hook_reads_minted_badge(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+    let event_id = create_event(&contract, alice.address()).await?;
+
+    // The hook reverts unless `hasAttended` already sees the badge, and the
+    // mint requires it to succeed.
+    let hook = read_back::deploy(&alice.wallet).await?;
+    watch!(contract.setEventMintHook(event_id, hook, true))?;
+    let receipt = receipt!(contract.mintToken(event_id, bob_addr))?;
+
+    assert!(receipt.emits(Poap::BadgeMinted {
+        eventId: event_id,
+        tokenId: uint!(1_U256),
+        attendee: bob_addr,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn mints_capped_role_badges(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let event_id = create_event(&contract, alice.address()).await?;
+
+    let receipt = receipt!(contract.addEventRole(event_id, "Speaker".to_string(), uint!(1_U256)))?;
+    let role_id = uint!(1_U256);
+    assert!(receipt.emits(Poap::EventRoleAdded {
+        eventId: event_id,
+        roleId: role_id,
+        name: "Speaker".to_string(),
+        supplyCap: uint!(1_U256),
+    }));
+
+    watch!(contract.mintTokenWithRole(event_id, bob.address(), role_id))?;
+    let Poap::getTokenRoleReturn { roleId } = contract.getTokenRole(uint!(1_U256)).call().await?;
+    assert_eq!(role_id, roleId);
+
+    let err = send!(contract.mintTokenWithRole(event_id, charlie.address(), role_id))
+        .expect_err("should not exceed the role supply cap");
+    assert!(err.reverted_with(Poap::RoleSupplyExceeded {
+        eventId: event_id,
+        roleId: role_id,
+        supplyCap: uint!(1_U256),
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn auto_mints_after_session_threshold(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    watch!(contract.createSessionEvent(
+        EVENT_NAME.to_string(),
+        alice.address(),
+        uint!(2_U256),
+        true
+    ))?;
+    let Poap::getLastEventIdReturn { eventId: event_id } = contract.getLastEventId().call().await?;
+    for name in ["Keynote", "Workshop"] {
+        watch!(contract.addEventSession(event_id, name.to_string()))?;
+    }
+
+    let receipt = receipt!(contract.checkIn(event_id, uint!(1_U256), bob_addr))?;
+    assert!(receipt.emits(Poap::SessionCheckedIn {
+        eventId: event_id,
+        sessionId: uint!(1_U256),
+        attendee: bob_addr,
+    }));
+    let Poap::balanceOfReturn { balance } = contract.balanceOf(bob_addr).call().await?;
+    assert_eq!(U256::ZERO, balance);
+
+    let receipt = receipt!(contract.checkIn(event_id, uint!(2_U256), bob_addr))?;
+    assert!(receipt.emits(Poap::BadgeMinted {
+        eventId: event_id,
+        tokenId: uint!(1_U256),
+        attendee: bob_addr,
+    }));
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: Soulbound
+// ============================================================================
+
+#[e2e::test]
+async fn error_when_transferring_or_approving(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract_alice = Poap::new(contract_addr, &alice.wallet);
+    let contract_bob = Poap::new(contract_addr, &bob.wallet);
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+
+    let event_id = create_event(&contract_alice, alice_addr).await?;
+    watch!(contract_alice.mintToken(event_id, bob_addr))?;
+    let token_id = uint!(1_U256);
+
+    let err = send!(contract_bob.transferFrom(bob_addr, alice_addr, token_id))
+        .expect_err("should not transfer a soulbound badge");
+    assert!(err.reverted_with(Poap::UnauthorizedAction {}));
+
+    let err = send!(contract_bob.safeTransferFrom(bob_addr, alice_addr, token_id))
+        .expect_err("should not safe transfer a soulbound badge");
+    assert!(err.reverted_with(Poap::UnauthorizedAction {}));
+
+    let err =
+        send!(contract_bob.safeTransferFromWithData(bob_addr, alice_addr, token_id, Bytes::new()))
+            .expect_err("should not safe transfer a soulbound badge with data");
+    assert!(err.reverted_with(Poap::UnauthorizedAction {}));
+
+    let err = send!(contract_bob.approve(alice_addr, token_id))
+        .expect_err("should not approve a soulbound badge");
+    assert!(err.reverted_with(Poap::UnauthorizedAction {}));
+
+    let err = send!(contract_bob.setApprovalForAll(alice_addr, true))
+        .expect_err("should not approve an operator");
+    assert!(err.reverted_with(Poap::UnauthorizedAction {}));
+
+    let Poap::ownerOfReturn { ownerOf } = contract_alice.ownerOf(token_id).call().await?;
+    assert_eq!(bob_addr, ownerOf);
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: Metadata and Enumeration
+// ============================================================================
+
+#[e2e::test]
+async fn token_uri_serves_metadata_json(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let alice_addr = alice.address();
+
+    create_event(&contract, alice_addr).await?;
+    let event_id = create_event(&contract, alice_addr).await?;
+    watch!(contract.mintToken(event_id, alice_addr))?;
+
+    let Poap::tokenURIReturn { tokenURI } = contract.tokenURI(uint!(1_U256)).call().await?;
+    assert!(tokenURI.starts_with("data:application/json;base64,"));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn records_token_check_in(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let alice_addr = alice.address();
+
+    let event_id = create_event(&contract, alice_addr).await?;
+    let receipt = receipt!(contract.mintToken(event_id, bob.address()))?;
+
+    let info = contract.getTokenInfo(uint!(1_U256)).call().await?;
+    assert_eq!(event_id, info._0);
+    assert!(info._1 > U256::ZERO);
+    assert_eq!(U256::from(receipt.block_number.unwrap()), info._2);
+    assert_eq!(alice_addr, info._3);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_token_uri_of_nonexistent_token(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let token_id = uint!(1_U256);
+
+    let err = contract
+        .tokenURI(token_id)
+        .call()
+        .await
+        .expect_err("should return `ERC721NonexistentToken`");
+
+    assert!(err.reverted_with(Poap::ERC721NonexistentToken { tokenId: token_id }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn enumerates_holder_badges(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let first = create_event(&contract, alice.address()).await?;
+    let second = create_event(&contract, alice.address()).await?;
+    watch!(contract.mintToken(first, bob_addr))?;
+    watch!(contract.mintToken(second, bob_addr))?;
+
+    let Poap::totalSupplyReturn { totalSupply } = contract.totalSupply().call().await?;
+    assert_eq!(uint!(2_U256), totalSupply);
+
+    let Poap::tokenOfOwnerByIndexReturn { tokenId } = contract
+        .tokenOfOwnerByIndex(bob_addr, uint!(1_U256))
+        .call()
+        .await?;
+    assert_eq!(uint!(2_U256), tokenId);
+
+    let Poap::getHolderBadgesReturn { badges } = contract
+        .getHolderBadges(bob_addr, U256::ZERO, uint!(10_U256))
+        .call()
+        .await?;
+    assert_eq!(2, badges.len());
+    assert_eq!((uint!(1_U256), first), (badges[0]._0, badges[0]._1));
+    assert_eq!(EVENT_NAME, badges[1]._2);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn reports_attendance(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let first = create_event(&contract, alice.address()).await?;
+    let second = create_event(&contract, alice.address()).await?;
+    watch!(contract.mintToken(first, bob_addr))?;
+
+    let Poap::hasAttendedReturn { attended } = contract.hasAttended(first, bob_addr).call().await?;
+    assert!(attended);
+    let Poap::hasAttendedReturn { attended } =
+        contract.hasAttended(second, bob_addr).call().await?;
+    assert!(!attended);
+
+    let Poap::hasAttendedAnyReturn { attended } = contract
+        .hasAttendedAny(vec![second, first], bob_addr)
+        .call()
+        .await?;
+    assert!(attended);
+
+    let Poap::attendedCountReturn { count } = contract.attendedCount(bob_addr).call().await?;
+    assert_eq!(uint!(1_U256), count);
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: ERC-165 Support Interface
+// ============================================================================
+
+#[e2e::test]
+async fn supports_interface(alice: Account) -> eyre::Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Poap::new(contract_addr, &alice.wallet);
+
+    let invalid_interface_id: u32 = 0x_ffffffff;
+    let Poap::supportsInterfaceReturn {
+        supportsInterface: supports_interface,
+    } = contract
+        .supportsInterface(invalid_interface_id.into())
+        .call()
+        .await?;
+    assert!(!supports_interface);
+
+    for interface_id in [
+        0x80ac58cd_u32, // ERC-721
+        0x5b5e139f_u32, // ERC-721 Metadata
+        0x780e9d63_u32, // ERC-721 Enumerable
+        0x66eb26eb_u32, // IPoapAttendance
+        0x01ffc9a7_u32, // ERC-165
+    ] {
+        let Poap::supportsInterfaceReturn {
+            supportsInterface: supports_interface,
+        } = contract
+            .supportsInterface(interface_id.into())
+            .call()
+            .await?;
+        assert!(supports_interface, "should support {interface_id:#010x}");
+    }
+
+    Ok(())
+}
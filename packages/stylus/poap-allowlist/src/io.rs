@@ -0,0 +1,181 @@
+use std::{fs, io::Read, path::Path, str::FromStr};
+
+use alloy::primitives::{Address, B256, U256};
+use eyre::{bail, eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Proofs file written by the CLI and served to claimants.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowlistFile {
+    pub root: B256,
+    pub entries: Vec<ProofEntry>,
+}
+
+/// An allowlisted pair with its leaf and proof.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofEntry {
+    #[serde(with = "decimal")]
+    pub event_id: U256,
+    pub address: Address,
+    pub leaf: B256,
+    pub proof: Vec<B256>,
+}
+
+impl AllowlistFile {
+    /// Returns the entry of `address` for `event_id`.
+    pub fn find(&self, event_id: U256, address: Address) -> Option<&ProofEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.event_id == event_id && entry.address == address)
+    }
+}
+
+/// Serializes event ids as decimal strings, as organizers write them.
+mod decimal {
+    use std::str::FromStr;
+
+    use alloy::primitives::U256;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        U256::from_str(&value).map_err(D::Error::custom)
+    }
+}
+
+/// Reads `(event_id, address)` pairs from a `.json` file or, for any other
+/// extension, a CSV file.
+pub fn read_entries(path: &Path) -> Result<Vec<(U256, Address)>> {
+    let contents =
+        fs::read_to_string(path).wrap_err_with(|| format!("cannot read {}", path.display()))?;
+    let is_json = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if is_json {
+        parse_json(&contents)
+    } else {
+        parse_csv(contents.as_bytes())
+    }
+}
+
+/// Parses CSV rows of `event_id,address`, with an optional header row.
+pub(crate) fn parse_csv(reader: impl Read) -> Result<Vec<(U256, Address)>> {
+    let mut csv = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let mut entries = Vec::new();
+    for (row, record) in csv.records().enumerate() {
+        let record = record?;
+        let line = record.position().map_or(0, |position| position.line());
+        let (Some(event_id), Some(address)) = (record.get(0), record.get(1)) else {
+            bail!("line {line}: expected `event_id,address`");
+        };
+        if row == 0 && U256::from_str(event_id).is_err() {
+            continue;
+        }
+        entries.push(parse_entry(event_id, address).wrap_err_with(|| format!("line {line}"))?);
+    }
+    Ok(entries)
+}
+
+/// Parses a JSON array of `{"eventId": ..., "address": ...}` objects or of
+/// `[eventId, address]` pairs. Event ids may be numbers or strings.
+pub(crate) fn parse_json(contents: &str) -> Result<Vec<(U256, Address)>> {
+    let Value::Array(items) = serde_json::from_str(contents)? else {
+        bail!("expected a JSON array");
+    };
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let (event_id, address) = match item {
+                Value::Object(fields) => (fields.get("eventId"), fields.get("address")),
+                Value::Array(pair) if pair.len() == 2 => (pair.first(), pair.get(1)),
+                _ => (None, None),
+            };
+            let (Some(event_id), Some(Value::String(address))) = (event_id, address) else {
+                bail!("item {index}: expected an `eventId` and an `address`");
+            };
+            let event_id = match event_id {
+                Value::Number(number) => number.to_string(),
+                Value::String(text) => text.clone(),
+                _ => bail!("item {index}: `eventId` must be a number or a string"),
+            };
+            parse_entry(&event_id, address).wrap_err_with(|| format!("item {index}"))
+        })
+        .collect()
+}
+
+fn parse_entry(event_id: &str, address: &str) -> Result<(U256, Address)> {
+    let event_id = U256::from_str(event_id).map_err(|_| eyre!("invalid event id `{event_id}`"))?;
+    let address = address
+        .parse()
+        .map_err(|_| eyre!("invalid address `{address}`"))?;
+    Ok((event_id, address))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    const ALICE: Address = address!("0x00000000000000000000000000000000000000a1");
+    const BOB: Address = address!("0x00000000000000000000000000000000000000b0");
+
+    #[test]
+    fn parses_csv_with_and_without_header() {
+        let expected = vec![(U256::from(1), ALICE), (U256::from(2), BOB)];
+
+        let csv = format!("event_id,address\n1,{ALICE}\n2, {BOB}\n");
+        assert_eq!(parse_csv(csv.as_bytes()).unwrap(), expected);
+
+        let csv = format!("1,{ALICE}\n2,{BOB}\n");
+        assert_eq!(parse_csv(csv.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn parses_json_objects_and_pairs() {
+        let json = format!(r#"[{{"eventId": 1, "address": "{ALICE}"}}, ["2", "{BOB}"]]"#);
+
+        assert_eq!(
+            parse_json(&json).unwrap(),
+            vec![(U256::from(1), ALICE), (U256::from(2), BOB)]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_rows() {
+        let err = parse_csv(format!("1,{ALICE}\n2,nope\n").as_bytes()).unwrap_err();
+        assert!(format!("{err:#}").contains("line 2"), "{err:#}");
+
+        let err = parse_json(r#"[{"eventId": 1}]"#).unwrap_err();
+        assert!(err.to_string().contains("item 0"), "{err}");
+    }
+
+    #[test]
+    fn file_round_trips_through_json() {
+        let file = AllowlistFile {
+            root: B256::repeat_byte(1),
+            entries: vec![ProofEntry {
+                event_id: U256::from(12),
+                address: ALICE,
+                leaf: B256::repeat_byte(2),
+                proof: vec![B256::repeat_byte(3)],
+            }],
+        };
+        let json = serde_json::to_string(&file).unwrap();
+
+        assert!(json.contains(r#""eventId":"12""#), "{json}");
+        assert_eq!(serde_json::from_str::<AllowlistFile>(&json).unwrap(), file);
+    }
+}
@@ -0,0 +1,300 @@
+//! Merkle allowlists of `(event_id, address)` pairs for `Poap` claims.
+//!
+//! The tree matches OpenZeppelin's `StandardMerkleTree` with leaf types
+//! `["uint256", "address"]`, and `Poap`'s `claimWithProof` verifies it:
+//!
+//! - a leaf is `keccak256(keccak256(abi.encode(uint256 eventId, address account)))`;
+//! - inner nodes hash their two children in ascending order, so proofs carry
+//!   no left/right flags;
+//! - leaves are sorted by hash before the tree is built, so the root does not
+//!   depend on the order of the input list.
+mod io;
+
+use std::collections::HashMap;
+
+use alloy::{
+    primitives::{keccak256, Address, B256, U256},
+    sol_types::SolValue,
+};
+
+pub use io::{read_entries, AllowlistFile, ProofEntry};
+
+/// Errors returned when building an [`Allowlist`].
+#[derive(Debug, thiserror::Error)]
+pub enum AllowlistError {
+    #[error("allowlist is empty")]
+    Empty,
+    #[error("address {account} is listed twice for event {event_id}")]
+    Duplicate { event_id: U256, account: Address },
+}
+
+/// Returns the leaf for `account` attending `event_id`.
+pub fn leaf(event_id: U256, account: Address) -> B256 {
+    keccak256(keccak256((event_id, account).abi_encode()))
+}
+
+/// Hashes two nodes in ascending order.
+pub fn hash_pair(a: B256, b: B256) -> B256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    keccak256([left.as_slice(), right.as_slice()].concat())
+}
+
+/// Returns whether `proof` proves that `leaf` is part of the tree with `root`.
+pub fn verify(proof: &[B256], root: B256, leaf: B256) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, &sibling| hash_pair(node, sibling))
+        == root
+}
+
+/// A Merkle tree over `(event_id, address)` pairs.
+#[derive(Debug, Clone)]
+pub struct Allowlist {
+    /// Nodes in level order: the root first and the leaves last.
+    tree: Vec<B256>,
+    /// Tree index of each entry's leaf.
+    positions: HashMap<(U256, Address), usize>,
+    /// Entries in input order.
+    entries: Vec<(U256, Address)>,
+}
+
+impl Allowlist {
+    /// Builds the tree of `entries`.
+    pub fn new(entries: Vec<(U256, Address)>) -> Result<Self, AllowlistError> {
+        if entries.is_empty() {
+            return Err(AllowlistError::Empty);
+        }
+
+        let mut leaves: Vec<(B256, (U256, Address))> = entries
+            .iter()
+            .map(|&(event_id, account)| (leaf(event_id, account), (event_id, account)))
+            .collect();
+        leaves.sort_unstable_by_key(|&(hash, _)| hash);
+
+        let mut tree = vec![B256::ZERO; 2 * leaves.len() - 1];
+        let mut positions = HashMap::with_capacity(leaves.len());
+        for (i, &(hash, entry)) in leaves.iter().enumerate() {
+            let position = tree.len() - 1 - i;
+            tree[position] = hash;
+            if positions.insert(entry, position).is_some() {
+                return Err(AllowlistError::Duplicate {
+                    event_id: entry.0,
+                    account: entry.1,
+                });
+            }
+        }
+        for i in (0..tree.len() - leaves.len()).rev() {
+            tree[i] = hash_pair(tree[2 * i + 1], tree[2 * i + 2]);
+        }
+
+        Ok(Self {
+            tree,
+            positions,
+            entries,
+        })
+    }
+
+    pub fn root(&self) -> B256 {
+        self.tree[0]
+    }
+
+    /// Returns the entries in input order.
+    pub fn entries(&self) -> &[(U256, Address)] {
+        &self.entries
+    }
+
+    /// Returns the proof for `account` attending `event_id`, or `None` if the
+    /// pair is not in the allowlist.
+    pub fn proof(&self, event_id: U256, account: Address) -> Option<Vec<B256>> {
+        let mut index = *self.positions.get(&(event_id, account))?;
+        let mut proof = Vec::new();
+        while index > 0 {
+            let sibling = if index % 2 == 1 { index + 1 } else { index - 1 };
+            proof.push(self.tree[sibling]);
+            index = (index - 1) / 2;
+        }
+        Some(proof)
+    }
+
+    /// Returns the root and every entry's proof, ready to be written out.
+    pub fn to_file(&self) -> AllowlistFile {
+        AllowlistFile {
+            root: self.root(),
+            entries: self
+                .entries
+                .iter()
+                .map(|&(event_id, account)| ProofEntry {
+                    event_id,
+                    address: account,
+                    leaf: leaf(event_id, account),
+                    proof: self.proof(event_id, account).unwrap_or_default(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{address, b256};
+
+    use super::*;
+
+    fn account(byte: u8) -> Address {
+        Address::repeat_byte(byte)
+    }
+
+    fn entries(count: u8) -> Vec<(U256, Address)> {
+        (1..=count)
+            .map(|i| (U256::from(i % 3 + 1), account(i)))
+            .collect()
+    }
+
+    #[test]
+    fn leaf_double_hashes_abi_encoding() {
+        let mut encoded = [0u8; 64];
+        encoded[31] = 7;
+        encoded[44..].copy_from_slice(account(0xaa).as_slice());
+
+        assert_eq!(
+            leaf(U256::from(7), account(0xaa)),
+            keccak256(keccak256(encoded))
+        );
+    }
+
+    #[test]
+    fn matches_standard_merkle_tree_readme_example() {
+        // `StandardMerkleTree.of(values, ["address", "uint256"])` from the
+        // @openzeppelin/merkle-tree README.
+        let first = (
+            address!("0x1111111111111111111111111111111111111111"),
+            U256::from(5_000_000_000_000_000_000_u128),
+        );
+        let second = (
+            address!("0x2222222222222222222222222222222222222222"),
+            U256::from(2_500_000_000_000_000_000_u128),
+        );
+        let first_leaf = keccak256(keccak256(first.abi_encode()));
+        let second_leaf = keccak256(keccak256(second.abi_encode()));
+        let root = b256!("0xd4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77");
+
+        assert_eq!(
+            second_leaf,
+            b256!("0xb92c48e9d7abe27fd8dfd6b5dfdbfb1c9a463f80c712b66f3a5180a090cccafc")
+        );
+        assert_eq!(hash_pair(first_leaf, second_leaf), root);
+        assert!(verify(&[second_leaf], root, first_leaf));
+    }
+
+    #[test]
+    fn matches_pinned_vector() {
+        // Shared with the allowlist claim tests of erc721-example, so the
+        // contract and this crate cannot drift apart.
+        let allowlist = Allowlist::new(vec![
+            (U256::from(1), account(0x11)),
+            (U256::from(1), account(0x22)),
+            (U256::from(2), account(0x33)),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            allowlist.root(),
+            b256!("0x04ae7f663bdad2d36b72e745482a05e9e5860e44e7cab625dc3dd8c1937c6c27")
+        );
+        assert_eq!(
+            leaf(U256::from(1), account(0x11)),
+            b256!("0xc2ceb1212bc8227979fe9f384b22d0c77a49547981a7a40b4dbb2886abdfe7b6")
+        );
+        assert_eq!(
+            allowlist.proof(U256::from(1), account(0x11)),
+            Some(vec![b256!(
+                "0xb1fb89aaa099fa54e70dd809a889439768410b8212f4e64cf6ce479a0e1b8933"
+            )])
+        );
+        assert_eq!(
+            allowlist.proof(U256::from(2), account(0x33)),
+            Some(vec![
+                b256!("0x7b4e4429a12d7c7f52f97ac3310a7f766c8a424a830a3b5423010430bc468c18"),
+                b256!("0xc2ceb1212bc8227979fe9f384b22d0c77a49547981a7a40b4dbb2886abdfe7b6"),
+            ])
+        );
+    }
+
+    #[test]
+    fn single_entry_root_is_its_leaf() {
+        let allowlist = Allowlist::new(entries(1)).unwrap();
+        let (event_id, account) = allowlist.entries()[0];
+
+        assert_eq!(allowlist.root(), leaf(event_id, account));
+        assert_eq!(allowlist.proof(event_id, account), Some(vec![]));
+    }
+
+    #[test]
+    fn two_entry_root_hashes_sorted_leaves() {
+        let allowlist = Allowlist::new(entries(2)).unwrap();
+        let leaves: Vec<B256> = allowlist
+            .entries()
+            .iter()
+            .map(|&(event_id, account)| leaf(event_id, account))
+            .collect();
+
+        assert_eq!(allowlist.root(), hash_pair(leaves[0], leaves[1]));
+        assert_eq!(
+            hash_pair(leaves[0], leaves[1]),
+            hash_pair(leaves[1], leaves[0])
+        );
+    }
+
+    #[test]
+    fn every_proof_verifies() {
+        for count in 1..=9 {
+            let allowlist = Allowlist::new(entries(count)).unwrap();
+            for &(event_id, account) in allowlist.entries() {
+                let proof = allowlist.proof(event_id, account).unwrap();
+                assert!(verify(&proof, allowlist.root(), leaf(event_id, account)));
+            }
+        }
+    }
+
+    #[test]
+    fn proofs_do_not_verify_other_entries() {
+        let allowlist = Allowlist::new(entries(5)).unwrap();
+        let (event_id, account) = allowlist.entries()[0];
+        let proof = allowlist.proof(event_id, account).unwrap();
+
+        assert!(!verify(
+            &proof,
+            allowlist.root(),
+            leaf(event_id + U256::from(1), account)
+        ));
+        assert!(!verify(
+            &proof,
+            allowlist.root(),
+            leaf(event_id, Address::ZERO)
+        ));
+        assert_eq!(allowlist.proof(event_id, Address::ZERO), None);
+    }
+
+    #[test]
+    fn root_ignores_input_order() {
+        let mut reversed = entries(6);
+        reversed.reverse();
+
+        assert_eq!(
+            Allowlist::new(entries(6)).unwrap().root(),
+            Allowlist::new(reversed).unwrap().root()
+        );
+    }
+
+    #[test]
+    fn rejects_empty_and_duplicate_lists() {
+        assert!(matches!(Allowlist::new(vec![]), Err(AllowlistError::Empty)));
+
+        let mut duplicated = entries(3);
+        duplicated.push(duplicated[1]);
+        assert!(matches!(
+            Allowlist::new(duplicated),
+            Err(AllowlistError::Duplicate { account: duplicate, .. }) if duplicate == account(2)
+        ));
+    }
+}
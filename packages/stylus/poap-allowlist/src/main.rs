@@ -0,0 +1,89 @@
+//! `poap-allowlist`: builds Merkle allowlists and serves their proofs.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use alloy::primitives::{Address, U256};
+use clap::{Parser, Subcommand};
+use eyre::{bail, eyre, Result, WrapErr};
+use poap_allowlist::{leaf, read_entries, verify, Allowlist, AllowlistFile};
+
+#[derive(Parser)]
+#[command(name = "poap-allowlist", about = "Build Poap Merkle allowlists")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Build the tree of a CSV or JSON list of `(event_id, address)` pairs,
+    /// print its root and write every proof to a JSON file.
+    Build {
+        input: PathBuf,
+        #[arg(long, short, default_value = "allowlist.json")]
+        out: PathBuf,
+    },
+    /// Print the proof of an address for an event from a proofs file.
+    Prove {
+        proofs: PathBuf,
+        event_id: U256,
+        address: Address,
+    },
+    /// Check every proof in a proofs file against its root.
+    Verify { proofs: PathBuf },
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Build { input, out } => {
+            let allowlist = Allowlist::new(read_entries(&input)?)?;
+            let json = serde_json::to_string_pretty(&allowlist.to_file())?;
+            fs::write(&out, json).wrap_err_with(|| format!("cannot write {}", out.display()))?;
+            println!("root     {}", allowlist.root());
+            println!("entries  {}", allowlist.entries().len());
+            println!("proofs   {}", out.display());
+        }
+        Command::Prove {
+            proofs,
+            event_id,
+            address,
+        } => {
+            let file = read_proofs(&proofs)?;
+            let entry = file
+                .find(event_id, address)
+                .ok_or_else(|| eyre!("{address} is not allowlisted for event {event_id}"))?;
+            println!("{}", serde_json::to_string_pretty(entry)?);
+        }
+        Command::Verify { proofs } => {
+            let file = read_proofs(&proofs)?;
+            let mut failed = 0;
+            for entry in &file.entries {
+                let expected = leaf(entry.event_id, entry.address);
+                if entry.leaf != expected || !verify(&entry.proof, file.root, expected) {
+                    failed += 1;
+                    println!(
+                        "invalid proof for {} at event {}",
+                        entry.address, entry.event_id
+                    );
+                }
+            }
+            if failed > 0 {
+                bail!("{failed} of {} proofs are invalid", file.entries.len());
+            }
+            println!(
+                "all {} proofs verify against {}",
+                file.entries.len(),
+                file.root
+            );
+        }
+    }
+    Ok(())
+}
+
+fn read_proofs(path: &Path) -> Result<AllowlistFile> {
+    let contents =
+        fs::read_to_string(path).wrap_err_with(|| format!("cannot read {}", path.display()))?;
+    Ok(serde_json::from_str(&contents)?)
+}
@@ -0,0 +1,98 @@
+use std::{path::Path, process::Command};
+
+use alloy::primitives::Address;
+use eyre::{bail, eyre, Result, WrapErr};
+
+/// Returns the size of the code `cargo stylus` uploads for the contract:
+/// the release WASM with its custom sections stripped, brotli-compressed.
+pub fn compressed_size(contract_dir: &Path, rpc_url: &str) -> Result<u64> {
+    let output = Command::new("cargo")
+        .args(["stylus", "check"])
+        .arg(format!("--endpoint={rpc_url}"))
+        .current_dir(contract_dir)
+        .output()
+        .wrap_err("cannot run cargo stylus check")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        bail!(
+            "cargo stylus check failed:\n{stdout}{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    contract_size(&stdout).ok_or_else(|| eyre!("no contract size in output:\n{stdout}"))
+}
+
+/// Deploys the contract with `cargo stylus deploy` and returns its address.
+/// It is an implementation to put behind a proxy, which initializes it.
+pub fn deploy(contract_dir: &Path, rpc_url: &str, private_key: &str) -> Result<Address> {
+    let output = Command::new("cargo")
+        .args(["stylus", "deploy", "--no-verify"])
+        .arg(format!("--endpoint={rpc_url}"))
+        .arg(format!("--private-key={private_key}"))
+        .current_dir(contract_dir)
+        .output()
+        .wrap_err("cannot run cargo stylus deploy")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        bail!(
+            "cargo stylus deploy failed:\n{stdout}{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    deployed_address(&stdout).ok_or_else(|| eyre!("no deployed address in output:\n{stdout}"))
+}
+
+/// Extracts the byte count from the `contract size: 23.3 KiB (23857 bytes)`
+/// line of `cargo stylus` output.
+fn contract_size(output: &str) -> Option<u64> {
+    output
+        .lines()
+        .filter(|line| line.contains("contract size:"))
+        .find_map(|line| {
+            let start = line.rfind('(')? + 1;
+            let end = start + line[start..].find(" bytes")?;
+            line[start..end].parse().ok()
+        })
+}
+
+/// Extracts the address from `cargo stylus deploy` output, as the deploy
+/// scripts do.
+fn deployed_address(output: &str) -> Option<Address> {
+    output
+        .lines()
+        .filter(|line| line.contains("deployed code at address:"))
+        .find_map(|line| {
+            let start = line.find("0x")?;
+            line.get(start..start + 42)?.parse().ok()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn parses_deployed_address() {
+        let output = "stripped custom section\n\
+            deployed code at address: \u{1b}[1;32m0xa6e41ffd769491a42a6e5ce453259b93983a22ef\u{1b}[0m\n\
+            deployment tx hash: 0x00";
+
+        assert_eq!(
+            deployed_address(output),
+            Some(address!("0xa6e41ffd769491a42a6e5ce453259b93983a22ef"))
+        );
+        assert_eq!(deployed_address("no address here"), None);
+    }
+
+    #[test]
+    fn parses_contract_size() {
+        let output = "stripped custom section from user wasm to remove any sensitive data\n\
+            contract size: \u{1b}[1;35m23.3 KiB (23857 bytes)\u{1b}[0m\n\
+            wasm size: 85.1 KiB (87185 bytes)";
+
+        assert_eq!(contract_size(output), Some(23857));
+        assert_eq!(contract_size("wasm size: 85.1 KiB (87185 bytes)"), None);
+    }
+}
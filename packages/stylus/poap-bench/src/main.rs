@@ -0,0 +1,210 @@
+//! `poap-bench`: measures gas of `Poap` entry points on the nitro devnode and
+//! the contract's compressed WASM size, and compares them with
+//! `baseline.json`, which has to be recorded on the devnode first.
+//!
+//! ```sh
+//! cargo run --release -- --save-baseline   # record a new baseline
+//! cargo run --release                      # compare against it
+//! ```
+mod contract;
+mod report;
+
+use std::path::PathBuf;
+
+use alloy::{
+    contract::SolCallBuilder,
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    sol_types::SolCall,
+};
+use clap::Parser;
+use eyre::{bail, Result};
+use poap_client::PoapClient;
+
+use report::Measurements;
+
+const DEVNODE_RPC_URL: &str = "http://127.0.0.1:8547";
+const DEVNODE_PRIVATE_KEY: &str =
+    "0xb6b15c8cb491557369f3c7d2c287b053eb229daa9c22138887752191c9520659";
+
+/// Number of mints averaged for the batch measurement.
+const BATCH_SIZE: usize = 10;
+
+#[derive(Parser)]
+#[command(name = "poap-bench", about = "Benchmark Poap gas and WASM size")]
+struct Cli {
+    #[arg(long, env = "RPC_URL", default_value = DEVNODE_RPC_URL)]
+    rpc_url: String,
+
+    #[arg(long, env = "PRIVATE_KEY", default_value = DEVNODE_PRIVATE_KEY, hide_default_value = true)]
+    private_key: String,
+
+    /// Directory of the contract crate.
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../erc721-example"))]
+    contract_dir: PathBuf,
+
+    /// Benchmark an already deployed contract instead of deploying one. The
+    /// signer must own it.
+    #[arg(long)]
+    contract: Option<Address>,
+
+    /// Attendee counts at which the event measurements are taken.
+    #[arg(long, value_delimiter = ',', default_value = "0,10,100")]
+    sizes: Vec<usize>,
+
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/baseline.json"))]
+    baseline: PathBuf,
+
+    /// Overwrite the baseline with this run's measurements.
+    #[arg(long)]
+    save_baseline: bool,
+
+    /// Allowed growth of any measurement, in percent.
+    #[arg(long, default_value_t = 2.0)]
+    tolerance: f64,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let signer: PrivateKeySigner = cli.private_key.parse()?;
+    let owner = signer.address();
+    let provider = ProviderBuilder::new()
+        .wallet(signer)
+        .connect_http(cli.rpc_url.parse()?);
+
+    let mut measurements = Measurements::new();
+
+    measurements.insert(
+        "wasm/compressed_bytes".to_owned(),
+        contract::compressed_size(&cli.contract_dir, &cli.rpc_url)?,
+    );
+
+    let client = match cli.contract {
+        Some(address) => PoapClient::new(address, provider),
+        None => {
+            let implementation =
+                contract::deploy(&cli.contract_dir, &cli.rpc_url, &cli.private_key)?;
+            PoapClient::deploy(
+                provider,
+                implementation,
+                "Poap Bench",
+                "BENCH",
+                "ipfs://",
+                owner,
+            )
+            .await?
+        }
+    };
+
+    for &size in &cli.sizes {
+        measure_event(&client, owner, size, &mut measurements).await?;
+    }
+
+    // A first run has no baseline to compare with, any other must have one.
+    let baseline = match report::read_baseline(&cli.baseline) {
+        Ok(baseline) => baseline,
+        Err(_) if cli.save_baseline => Measurements::new(),
+        Err(err) => return Err(err.wrap_err("record a baseline with --save-baseline")),
+    };
+    if baseline.is_empty() && !cli.save_baseline {
+        bail!(
+            "{} has no measurements, record them with --save-baseline",
+            cli.baseline.display()
+        );
+    }
+    let comparisons = report::compare(&baseline, &measurements);
+    println!(
+        "{:<44} {:>12} {:>12} {:>9}",
+        "measurement", "current", "baseline", "change"
+    );
+    for comparison in &comparisons {
+        println!("{comparison}");
+    }
+
+    if cli.save_baseline {
+        report::write_baseline(&cli.baseline, &measurements)?;
+        println!("saved baseline to {}", cli.baseline.display());
+        return Ok(());
+    }
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.regressed(cli.tolerance))
+        .count();
+    if regressions > 0 {
+        bail!(
+            "{regressions} measurements regressed by more than {}%",
+            cli.tolerance
+        );
+    }
+    Ok(())
+}
+
+/// Creates an event with `size` attendees and measures its entry points.
+async fn measure_event<P: Provider>(
+    client: &PoapClient<P>,
+    owner: Address,
+    size: usize,
+    measurements: &mut Measurements,
+) -> Result<()> {
+    let poap = client.instance();
+    let label = |name: &str| format!("gas/{name}/attendees={size}");
+
+    let event_id = client.create_event("Bench", owner).await?;
+    let results = client
+        .mint_to_many(
+            event_id,
+            &attendees(0, size),
+            poap_client::DEFAULT_MINT_CHUNK_SIZE,
+        )
+        .await;
+    if let Some(err) = results.into_iter().find_map(Result::err) {
+        bail!("cannot fill event {event_id}: {err}");
+    }
+
+    let create_event = gas_used(poap.createEvent("Bench".to_owned(), owner)).await?;
+    measurements.insert(label("create_event"), create_event);
+
+    let minter = Address::repeat_byte(0xbe);
+    let add_event_minter = gas_used(poap.addEventMinter(event_id, minter)).await?;
+    measurements.insert(label("add_event_minter"), add_event_minter);
+
+    let next = attendees(size, BATCH_SIZE + 1);
+    let mint_token = gas_used(poap.mintToken(event_id, next[0])).await?;
+    measurements.insert(label("mint_token"), mint_token);
+
+    let mut batch = 0;
+    for &attendee in &next[1..] {
+        batch += gas_used(poap.mintToken(event_id, attendee)).await?;
+    }
+    measurements.insert(label("mint_token_batch_avg"), batch / BATCH_SIZE as u64);
+
+    let token_id = client.last_token_id().await?;
+    let token_uri = poap.tokenURI(token_id).estimate_gas().await?;
+    measurements.insert(label("tokenURI"), token_uri);
+
+    let page = poap
+        .getEventAttendees(event_id, U256::ZERO, U256::from(100))
+        .estimate_gas()
+        .await?;
+    measurements.insert(label("getEventAttendees_100"), page);
+
+    Ok(())
+}
+
+/// Sends `call` and returns the gas used by its transaction.
+async fn gas_used<P: Provider, C: SolCall>(call: SolCallBuilder<&P, C>) -> Result<u64> {
+    let receipt = call.send().await?.get_receipt().await?;
+    if !receipt.status() {
+        bail!("transaction {} reverted", receipt.transaction_hash);
+    }
+    Ok(receipt.gas_used)
+}
+
+/// Returns `count` distinct attendee addresses starting at index `from`.
+fn attendees(from: usize, count: usize) -> Vec<Address> {
+    (from..from + count)
+        .map(|index| Address::left_padding_from(&(index as u64 + 1).to_be_bytes()))
+        .collect()
+}
@@ -0,0 +1,98 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use eyre::{Result, WrapErr};
+
+/// Named measurements, e.g. `gas/mint_token/attendees=100` or
+/// `wasm/compressed_bytes`.
+pub type Measurements = BTreeMap<String, u64>;
+
+pub fn read_baseline(path: &Path) -> Result<Measurements> {
+    let contents =
+        fs::read_to_string(path).wrap_err_with(|| format!("cannot read {}", path.display()))?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+pub fn write_baseline(path: &Path, measurements: &Measurements) -> Result<()> {
+    let json = serde_json::to_string_pretty(measurements)? + "\n";
+    fs::write(path, json).wrap_err_with(|| format!("cannot write {}", path.display()))
+}
+
+/// A measurement compared with its baseline value.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Comparison<'a> {
+    pub name: &'a str,
+    pub baseline: Option<u64>,
+    pub current: u64,
+}
+
+impl Comparison<'_> {
+    /// Change relative to the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline.filter(|&baseline| baseline > 0)?;
+        Some((self.current as f64 - baseline as f64) * 100.0 / baseline as f64)
+    }
+
+    /// Whether the measurement grew by more than `tolerance` percent.
+    pub fn regressed(&self, tolerance: f64) -> bool {
+        self.change().is_some_and(|change| change > tolerance)
+    }
+}
+
+impl fmt::Display for Comparison<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<44} {:>12}", self.name, self.current)?;
+        match (self.baseline, self.change()) {
+            (Some(baseline), Some(change)) => write!(f, " {baseline:>12} {change:>+8.2}%"),
+            (Some(baseline), None) => write!(f, " {baseline:>12}"),
+            (None, _) => write!(f, " {:>12}", "new"),
+        }
+    }
+}
+
+/// Pairs every current measurement with its baseline value, by name.
+pub fn compare<'a>(baseline: &Measurements, current: &'a Measurements) -> Vec<Comparison<'a>> {
+    current
+        .iter()
+        .map(|(name, &current)| Comparison {
+            name,
+            baseline: baseline.get(name).copied(),
+            current,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurements(values: &[(&str, u64)]) -> Measurements {
+        values
+            .iter()
+            .map(|&(name, value)| (name.to_owned(), value))
+            .collect()
+    }
+
+    #[test]
+    fn flags_regressions_beyond_tolerance() {
+        let baseline = measurements(&[("a", 100), ("b", 100), ("c", 100)]);
+        let current = measurements(&[("a", 101), ("b", 110), ("c", 90)]);
+
+        let regressed: Vec<&str> = compare(&baseline, &current)
+            .into_iter()
+            .filter(|comparison| comparison.regressed(2.0))
+            .map(|comparison| comparison.name)
+            .collect();
+
+        assert_eq!(regressed, vec!["b"]);
+    }
+
+    #[test]
+    fn new_measurements_never_regress() {
+        let current = measurements(&[("a", 100)]);
+        let comparisons = compare(&Measurements::new(), &current);
+
+        assert_eq!(comparisons[0].baseline, None);
+        assert!(!comparisons[0].regressed(0.0));
+        assert!(comparisons[0].to_string().ends_with("new"));
+    }
+}
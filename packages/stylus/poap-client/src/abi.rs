@@ -0,0 +1,221 @@
+//! Solidity ABI of the `Poap` contract.
+#![allow(missing_docs)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc, all_derives)]
+    contract Poap {
+        function initialize(string memory name, string memory symbol, string memory base_uri, address owner) external;
+
+        // Event management
+        function createEvent(string memory name, address organizer) external returns (uint256);
+        function addEventMinter(uint256 eventId, address minter) external;
+        function setEventMintHook(uint256 eventId, address hook, bool revertOnFailure) external;
+        function addEventRole(uint256 eventId, string memory name, uint256 supplyCap) external returns (uint256);
+        function setRoleMinter(uint256 eventId, uint256 roleId, address minter, bool allowed) external;
+        function createSessionEvent(string memory name, address organizer, uint256 sessionThreshold, bool autoMint) external returns (uint256);
+        function addEventSession(uint256 eventId, string memory name) external returns (uint256);
+        function checkIn(uint256 eventId, uint256 sessionId, address attendee) external returns (uint256);
+        function claimEventBadge(uint256 eventId) external returns (uint256);
+        function setEventAllowlistRoot(uint256 eventId, bytes32 root) external;
+        function claimWithProof(uint256 eventId, bytes32[] memory proof) external returns (uint256);
+        function mintToken(uint256 eventId, address to) external returns (uint256);
+        function mintTokenWithRole(uint256 eventId, address to, uint256 roleId) external returns (uint256);
+        function mintTokenSafe(uint256 eventId, address to, bytes calldata data) external returns (uint256);
+        function revokeBadge(uint256 tokenId) external;
+
+        // Achievements
+        function createAchievement(string memory name, uint256[] memory eventIds, uint256 threshold) external returns (uint256);
+        function setAchievementActive(uint256 achievementId, bool active) external;
+        function claimAchievement(uint256 achievementId) external returns (uint256);
+
+        // Soulbound overrides
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+        function safeTransferFromWithData(address from, address to, uint256 tokenId, bytes calldata data) external;
+        function transferFrom(address from, address to, uint256 tokenId) external;
+        function approve(address to, uint256 tokenId) external;
+        function setApprovalForAll(address operator, bool approved) external;
+
+        // ERC721 reads
+        function balanceOf(address owner) external view returns (uint256 balance);
+        function ownerOf(uint256 tokenId) external view returns (address ownerOf);
+        function getApproved(uint256 tokenId) external view returns (address approved);
+        function isApprovedForAll(address owner, address operator) external view returns (bool approved);
+        function name() external view returns (string memory name);
+        function symbol() external view returns (string memory symbol);
+        function tokenURI(uint256 tokenId) external view returns (string memory tokenURI);
+
+        // Admin
+        function transferOwnership(address newOwner) external;
+        function getOwner() external view returns (address owner);
+
+        // Meta-transactions (ERC-2771)
+        function setTrustedForwarder(address forwarder) external;
+        function getTrustedForwarder() external view returns (address forwarder);
+        function isTrustedForwarder(address forwarder) external view returns (bool trusted);
+        function executeForwarded(bytes calldata data) external returns (bytes memory);
+
+        // Upgrades
+        function upgradeToAndCall(address newImplementation, bytes calldata data) external payable;
+        function proxiableUUID() external view returns (bytes32 slot);
+        function getImplementation() external view returns (address implementation);
+
+        // Event views
+        function getEventName(uint256 eventId) external view returns (string memory name);
+        function isEventMinter(uint256 eventId, address minter) external view returns (bool isMinter);
+        function getEventOrganizer(uint256 eventId) external view returns (address organizer);
+        function isEventActive(uint256 eventId) external view returns (bool active);
+        function getEventRoleCount(uint256 eventId) external view returns (uint256 count);
+        function getEventRole(uint256 eventId, uint256 roleId) external view returns (uint256, string memory, uint256, uint256);
+        function isRoleMinter(uint256 eventId, uint256 roleId, address minter) external view returns (bool isMinter);
+        function getTokenRole(uint256 tokenId) external view returns (uint256 roleId);
+        function getEventSessionThreshold(uint256 eventId) external view returns (uint256 threshold);
+        function isEventAutoMint(uint256 eventId) external view returns (bool autoMint);
+        function getEventSessionCount(uint256 eventId) external view returns (uint256 count);
+        function getEventSessionName(uint256 eventId, uint256 sessionId) external view returns (string memory name);
+        function hasCheckedIn(uint256 eventId, uint256 sessionId, address attendee) external view returns (bool checkedIn);
+        function getAttendedSessionCount(uint256 eventId, address attendee) external view returns (uint256 attended);
+        function getEventMintHook(uint256 eventId) external view returns (address hook);
+        function isEventMintHookRequired(uint256 eventId) external view returns (bool required);
+        function getEventAllowlistRoot(uint256 eventId) external view returns (bytes32 root);
+        function getLastTokenId() external view returns (uint256 tokenId);
+        function getLastEventId() external view returns (uint256 eventId);
+        function getTokenEvent(uint256 tokenId) external view returns (uint256 eventId);
+        function getTokenInfo(uint256 tokenId) external view returns (uint256, uint256, uint256, address);
+        function getEvent(uint256 eventId) external view returns (uint256, string memory, address, bool, uint256);
+        function getEvents(uint256 offset, uint256 limit) external view returns ((uint256, string, address, bool, uint256)[] memory events);
+        function getOrganizerEventCount(address organizer) external view returns (uint256 count);
+        function getEventsByOrganizer(address organizer, uint256 offset, uint256 limit) external view returns ((uint256, string, address, bool, uint256)[] memory events);
+        function getEventAttendeeCount(uint256 eventId) external view returns (uint256 count);
+        function getEventAttendeeListLength(uint256 eventId) external view returns (uint256 length);
+        function getEventAttendees(uint256 eventId, uint256 offset, uint256 limit) external view returns (address[] memory attendees);
+        function getHolderBadges(address owner, uint256 offset, uint256 limit) external view returns ((uint256, uint256, string)[] memory badges);
+
+        // Attendance (IPoapAttendance)
+        function hasAttended(uint256 eventId, address account) external view returns (bool attended);
+        function hasAttendedAny(uint256[] memory eventIds, address account) external view returns (bool attended);
+        function attendedCount(address account) external view returns (uint256 count);
+
+        // Achievement views
+        function getLastAchievementId() external view returns (uint256 achievementId);
+        function getAchievementName(uint256 achievementId) external view returns (string memory name);
+        function getAchievementEvents(uint256 achievementId) external view returns (uint256[] memory eventIds);
+        function getAchievementThreshold(uint256 achievementId) external view returns (uint256 threshold);
+        function isAchievementActive(uint256 achievementId) external view returns (bool active);
+        function hasClaimedAchievement(uint256 achievementId, address account) external view returns (bool claimed);
+        function getAchievementProgress(uint256 achievementId, address account) external view returns (uint256 attended);
+        function isAchievementToken(uint256 tokenId) external view returns (bool isAchievement);
+
+        // ERC721Enumerable functions
+        function totalSupply() external view returns (uint256 totalSupply);
+        function tokenOfOwnerByIndex(address owner, uint256 index) external view returns (uint256 tokenId);
+        function tokenByIndex(uint256 index) external view returns (uint256 tokenId);
+
+        function supportsInterface(bytes4 interface_id) external view returns (bool supportsInterface);
+
+        error Error(string message);
+        error Panic(uint256 code);
+
+        error UnauthorizedAction();
+        error EventNotFound(uint256 eventId);
+        error EventInactive(uint256 eventId);
+        error AlreadyAttended(uint256 eventId, address attendee);
+        error NotEventMinter(uint256 eventId, address account);
+        error AchievementNotFound(uint256 achievementId);
+        error AchievementInactive(uint256 achievementId);
+        error AchievementAlreadyClaimed(uint256 achievementId, address account);
+        error AchievementNotEarned(uint256 achievementId, address account, uint256 attended, uint256 threshold);
+        error InvalidAchievementThreshold(uint256 threshold, uint256 eventCount);
+        error DuplicateAchievementEvent(uint256 eventId);
+        error AlreadyInitialized();
+        error ERC1967InvalidImplementation(address implementation);
+        error UUPSUnsupportedProxiableUUID(bytes32 slot);
+        error UUPSUnauthorizedCallContext();
+        error FailedCall();
+        error UntrustedForwarder(address forwarder);
+        error UnsupportedForwardedCall(bytes4 selector);
+        error InvalidForwardedCall();
+        error NotEventOrganizer(uint256 eventId, address account);
+        error MintHookReverted(uint256 eventId, address hook);
+        error RoleNotFound(uint256 eventId, uint256 roleId);
+        error NotRoleMinter(uint256 eventId, uint256 roleId, address account);
+        error RoleSupplyExceeded(uint256 eventId, uint256 roleId, uint256 supplyCap);
+        error InvalidSessionThreshold(uint256 threshold);
+        error NotSessionEvent(uint256 eventId);
+        error SessionNotFound(uint256 eventId, uint256 sessionId);
+        error AlreadyCheckedIn(uint256 eventId, uint256 sessionId, address attendee);
+        error SessionThresholdNotMet(uint256 eventId, address attendee, uint256 attended, uint256 threshold);
+        error InvalidAllowlistProof(uint256 eventId, address account);
+
+        error OwnableUnauthorizedAccount(address account);
+        error OwnableInvalidOwner(address owner);
+
+        error InvalidReceiverWithReason(string message);
+        error ERC721IncorrectOwner(address sender, uint256 tokenId, address owner);
+        error ERC721InsufficientApproval(address operator, uint256 tokenId);
+        error ERC721InvalidApprover(address approver);
+        error ERC721InvalidOperator(address operator);
+        error ERC721InvalidOwner(address owner);
+        error ERC721InvalidReceiver(address receiver);
+        error ERC721InvalidSender(address sender);
+        error ERC721NonexistentToken(uint256 tokenId);
+        error ERC721OutOfBoundsIndex(address owner, uint256 index);
+        error ERC721EnumerableForbiddenBatchMint();
+
+        event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+        event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+        event EventCreated(uint256 indexed eventId, address indexed organizer, string name);
+        event EventMinterAdded(uint256 indexed eventId, address indexed minter);
+        event BadgeMinted(uint256 indexed eventId, uint256 indexed tokenId, address indexed attendee);
+        event Upgraded(address indexed implementation);
+        event TrustedForwarderSet(address indexed forwarder);
+        event EventMintHookSet(uint256 indexed eventId, address indexed hook, bool revertOnFailure);
+        event MintHookFailed(uint256 indexed eventId, uint256 indexed tokenId, address indexed hook);
+        event EventRoleAdded(uint256 indexed eventId, uint256 indexed roleId, string name, uint256 supplyCap);
+        event RoleMinterSet(uint256 indexed eventId, uint256 indexed roleId, address indexed minter, bool allowed);
+        event SessionAdded(uint256 indexed eventId, uint256 indexed sessionId, string name);
+        event SessionCheckedIn(uint256 indexed eventId, uint256 indexed sessionId, address indexed attendee);
+        event AchievementCreated(uint256 indexed achievementId, string name, uint256 threshold);
+        event EventAllowlistRootSet(uint256 indexed eventId, bytes32 root);
+    }
+);
+
+sol!(
+    #[sol(rpc, all_derives)]
+    contract PoapFactory {
+        constructor(address implementation);
+
+        function createCollection(string memory name, string memory symbol, string memory base_uri, address owner) external returns (address);
+        function createCollectionDeterministic(uint256 organizationId, string memory name, string memory symbol, string memory base_uri, address owner) external returns (address);
+
+        function predictCollectionAddress(address creator, uint256 organizationId, string memory name, string memory symbol, string memory base_uri, address owner) external view returns (address collection);
+        function getImplementation() external view returns (address implementation);
+        function getCollectionCount() external view returns (uint256 count);
+        function getCollections(uint256 offset, uint256 limit) external view returns (address[] memory collections);
+        function getCreatorCollectionCount(address creator) external view returns (uint256 count);
+        function getCreatorCollections(address creator, uint256 offset, uint256 limit) external view returns (address[] memory collections);
+        function getCollectionCreator(address collection) external view returns (address creator);
+        function isCollection(address collection) external view returns (bool isCollection);
+
+        error InvalidImplementation(address implementation);
+        error InvalidCollectionOwner(address owner);
+        error CollectionDeploymentFailed();
+        error CollectionInitializationFailed(address collection);
+
+        event CollectionCreated(address indexed creator, address indexed collection, address indexed owner, string name, string symbol);
+    }
+);
+
+/// Creation code of the hand-assembled ERC-1967 proxy. `scripts/deploy_proxy.ts`
+/// and the erc721-example e2e tests deploy the same file; the script has the
+/// listing.
+pub const ERC1967_PROXY_BYTECODE: &str = include_str!("../../scripts/erc1967_proxy.hex");
+
+sol!(
+    #[sol(rpc, all_derives)]
+    contract Erc1967Proxy {
+        constructor(address implementation, bytes memory data);
+
+        event Upgraded(address indexed implementation);
+    }
+);
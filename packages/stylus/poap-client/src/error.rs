@@ -0,0 +1,54 @@
+use alloy::{
+    contract,
+    primitives::TxHash,
+    providers::PendingTransactionError,
+    transports::{RpcError, TransportErrorKind},
+};
+
+use crate::abi::Poap::PoapErrors;
+
+/// Errors returned by [`crate::PoapClient`].
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The contract reverted with one of its declared errors.
+    #[error("Poap reverted with {0:?}")]
+    Revert(PoapErrors),
+    /// The call failed for a reason other than a decodable revert.
+    #[error(transparent)]
+    Contract(contract::Error),
+    /// The transaction was sent but its receipt could not be retrieved.
+    #[error(transparent)]
+    PendingTransaction(#[from] PendingTransactionError),
+    /// A raw RPC request failed.
+    #[error(transparent)]
+    Rpc(#[from] RpcError<TransportErrorKind>),
+    /// The transaction was mined but reverted.
+    #[error("transaction {0} reverted")]
+    Reverted(TxHash),
+    /// A mint transaction succeeded without emitting a `Transfer` log.
+    #[error("transaction {0} did not mint a token")]
+    MissingTransfer(TxHash),
+    /// A transaction that creates something succeeded without emitting the
+    /// log carrying the new id.
+    #[error("transaction {1} did not emit {0}")]
+    MissingLog(&'static str, TxHash),
+}
+
+impl ClientError {
+    /// Returns the decoded contract error, if the call reverted with one.
+    pub fn as_revert(&self) -> Option<&PoapErrors> {
+        match self {
+            ClientError::Revert(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<contract::Error> for ClientError {
+    fn from(err: contract::Error) -> Self {
+        match err.as_decoded_interface_error::<PoapErrors>() {
+            Some(revert) => ClientError::Revert(revert),
+            None => ClientError::Contract(err),
+        }
+    }
+}
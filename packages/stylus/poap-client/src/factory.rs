@@ -0,0 +1,112 @@
+//! Off-chain counterparts of the `PoapFactory` address derivation, matching
+//! `poap-factory`'s `collection_salt` and `predict_clone_address`.
+use alloy::{
+    primitives::{hex, keccak256, Address, B256, U256},
+    sol_types::SolValue,
+};
+
+const CLONE_PREFIX: [u8; 20] = hex!("3d602d80600a3d3981f3363d3d373d3d3d363d73");
+const CLONE_SUFFIX: [u8; 15] = hex!("5af43d82803e903d91602b57fd5bf3");
+
+/// Returns the creation code of an EIP-1167 minimal proxy that delegates
+/// every call to `implementation`.
+pub fn clone_code(implementation: Address) -> Vec<u8> {
+    [
+        CLONE_PREFIX.as_slice(),
+        implementation.as_slice(),
+        CLONE_SUFFIX.as_slice(),
+    ]
+    .concat()
+}
+
+/// Returns the CREATE2 salt `createCollectionDeterministic` uses when
+/// `creator` calls it.
+pub fn collection_salt(
+    creator: Address,
+    organization_id: U256,
+    name: &str,
+    symbol: &str,
+    base_uri: &str,
+    owner: Address,
+) -> B256 {
+    keccak256((creator, organization_id, name, symbol, base_uri, owner).abi_encode_params())
+}
+
+/// Returns the address `factory` deploys the collection with `salt`, from
+/// [`collection_salt`], to, as `predictCollectionAddress` does. The result is
+/// the same on every chain where the factory and implementation share their
+/// addresses.
+pub fn predict_collection_address(
+    factory: Address,
+    implementation: Address,
+    salt: B256,
+) -> Address {
+    factory.create2(salt, keccak256(clone_code(implementation)))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    const FACTORY: Address = address!("0x00000000000000000000000000000000000000fa");
+    const IMPLEMENTATION: Address = address!("0x00000000000000000000000000000000000000aa");
+    const OWNER: Address = address!("0x00000000000000000000000000000000000000b0");
+    const CREATOR: Address = address!("0x00000000000000000000000000000000000000c0");
+
+    fn salt(creator: Address) -> B256 {
+        collection_salt(
+            creator,
+            U256::from(7),
+            "ETHGlobal",
+            "ETHG",
+            "ipfs://",
+            OWNER,
+        )
+    }
+
+    #[test]
+    fn salt_abi_encodes_arguments() {
+        let encoded = (
+            CREATOR,
+            U256::from(7),
+            "ETHGlobal".to_owned(),
+            "ETHG".to_owned(),
+            "ipfs://".to_owned(),
+            OWNER,
+        )
+            .abi_encode_params();
+
+        assert_eq!(salt(CREATOR), keccak256(encoded));
+        assert_ne!(salt(CREATOR), salt(OWNER));
+    }
+
+    #[test]
+    fn predicts_create2_address_of_clone() {
+        let predicted = predict_collection_address(FACTORY, IMPLEMENTATION, salt(CREATOR));
+
+        let preimage = [
+            &[0xff][..],
+            FACTORY.as_slice(),
+            salt(CREATOR).as_slice(),
+            keccak256(clone_code(IMPLEMENTATION)).as_slice(),
+        ]
+        .concat();
+        assert_eq!(predicted, Address::from_slice(&keccak256(preimage)[12..]));
+        assert_eq!(clone_code(IMPLEMENTATION).len(), 55);
+    }
+
+    #[test]
+    fn address_depends_on_factory_and_implementation() {
+        let predict = |factory, implementation| {
+            predict_collection_address(factory, implementation, salt(CREATOR))
+        };
+
+        assert_ne!(
+            predict(FACTORY, IMPLEMENTATION),
+            predict(OWNER, IMPLEMENTATION)
+        );
+        assert_ne!(predict(FACTORY, IMPLEMENTATION), predict(FACTORY, OWNER));
+    }
+}
@@ -0,0 +1,999 @@
+//! Typed async client for the `Poap` Stylus contract.
+//!
+//! [`PoapClient`] wraps the generated [`abi::Poap`] binding with one method per
+//! public contract function, decodes reverts into [`abi::Poap::PoapErrors`]
+//! and decodes the contract's logs into [`abi::Poap::PoapEvents`].
+pub mod abi;
+mod error;
+pub mod factory;
+
+use alloy::{
+    contract::{RawCallBuilder, SolCallBuilder},
+    hex,
+    primitives::{Address, Bytes, B256, U256},
+    providers::Provider,
+    rpc::types::{Log, TransactionReceipt},
+    sol_types::{SolCall, SolConstructor, SolEvent, SolEventInterface},
+};
+
+pub use abi::Poap::{PoapErrors, PoapEvents};
+pub use error::ClientError;
+
+use abi::{
+    Erc1967Proxy,
+    Poap::{self, PoapInstance},
+    ERC1967_PROXY_BYTECODE,
+};
+
+/// Default number of mint transactions [`PoapClient::mint_to_many`] keeps in
+/// flight before waiting for their receipts.
+pub const DEFAULT_MINT_CHUNK_SIZE: usize = 20;
+
+/// ERC-165 id of `IPoapAttendance`, for [`PoapClient::supports_interface`].
+pub const ATTENDANCE_INTERFACE_ID: [u8; 4] = [0x66, 0xeb, 0x26, 0xeb];
+
+/// Event fields as returned by `getEvent` and the paginated event views.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventInfo {
+    pub id: U256,
+    pub name: String,
+    pub organizer: Address,
+    pub active: bool,
+    pub attendee_count: U256,
+}
+
+impl From<(U256, String, Address, bool, U256)> for EventInfo {
+    fn from(
+        (id, name, organizer, active, attendee_count): (U256, String, Address, bool, U256),
+    ) -> Self {
+        Self {
+            id,
+            name,
+            organizer,
+            active,
+            attendee_count,
+        }
+    }
+}
+
+/// A badge held by an account, as returned by `getHolderBadges`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolderBadge {
+    pub token_id: U256,
+    pub event_id: U256,
+    pub event_name: String,
+}
+
+impl From<(U256, U256, String)> for HolderBadge {
+    fn from((token_id, event_id, event_name): (U256, U256, String)) -> Self {
+        Self {
+            token_id,
+            event_id,
+            event_name,
+        }
+    }
+}
+
+/// Badge role of an event, as returned by `getEventRole`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRole {
+    pub id: U256,
+    pub name: String,
+    /// Maximum number of badges of this role; zero means uncapped.
+    pub supply_cap: U256,
+    pub supply: U256,
+}
+
+impl From<(U256, String, U256, U256)> for EventRole {
+    fn from((id, name, supply_cap, supply): (U256, String, U256, U256)) -> Self {
+        Self {
+            id,
+            name,
+            supply_cap,
+            supply,
+        }
+    }
+}
+
+/// Check-in record of a badge, as returned by `getTokenInfo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub event_id: U256,
+    pub minted_at: U256,
+    pub block_number: U256,
+    pub minter: Address,
+}
+
+impl From<(U256, U256, U256, Address)> for TokenInfo {
+    fn from((event_id, minted_at, block_number, minter): (U256, U256, U256, Address)) -> Self {
+        Self {
+            event_id,
+            minted_at,
+            block_number,
+            minter,
+        }
+    }
+}
+
+/// Decodes a log emitted by the `Poap` contract.
+///
+/// Returns `None` for logs that do not match any `Poap` event.
+pub fn decode_log(log: &Log) -> Option<PoapEvents> {
+    PoapEvents::decode_log(&log.inner).ok().map(|log| log.data)
+}
+
+/// Returns the ids of all tokens minted by `contract` in `receipt`.
+pub fn minted_token_ids(contract: Address, receipt: &TransactionReceipt) -> Vec<U256> {
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .filter(|log| log.address() == contract)
+        .filter_map(|log| Poap::Transfer::decode_log_data(log.data()).ok())
+        .filter(|transfer| transfer.from == Address::ZERO)
+        .map(|transfer| transfer.tokenId)
+        .collect()
+}
+
+fn proxy_bytecode() -> Vec<u8> {
+    hex::decode(ERC1967_PROXY_BYTECODE.trim()).expect("proxy bytecode is valid hex")
+}
+
+/// Typed client for a deployed `Poap` contract.
+#[derive(Debug, Clone)]
+pub struct PoapClient<P> {
+    contract: PoapInstance<P>,
+}
+
+impl<P: Provider> PoapClient<P> {
+    /// Creates a client for the contract deployed at `address`.
+    pub fn new(address: Address, provider: P) -> Self {
+        Self {
+            contract: Poap::new(address, provider),
+        }
+    }
+
+    /// Deploys an ERC-1967 proxy in front of the `Poap` `implementation`,
+    /// initializing it in the same transaction, and returns a client for the
+    /// proxy.
+    pub async fn deploy(
+        provider: P,
+        implementation: Address,
+        name: &str,
+        symbol: &str,
+        base_uri: &str,
+        owner: Address,
+    ) -> Result<Self, ClientError> {
+        let data = Poap::initializeCall {
+            name: name.to_owned(),
+            symbol: symbol.to_owned(),
+            base_uri: base_uri.to_owned(),
+            owner,
+        }
+        .abi_encode();
+        let mut code = proxy_bytecode();
+        code.extend(
+            Erc1967Proxy::constructorCall {
+                implementation,
+                data: data.into(),
+            }
+            .abi_encode(),
+        );
+        let address = RawCallBuilder::new_raw_deploy(&provider, code.into())
+            .deploy()
+            .await?;
+        Ok(Self::new(address, provider))
+    }
+
+    /// Returns the contract address.
+    pub fn address(&self) -> Address {
+        *self.contract.address()
+    }
+
+    /// Returns the underlying provider.
+    pub fn provider(&self) -> &P {
+        self.contract.provider()
+    }
+
+    /// Returns the generated binding, for calls not covered by the client.
+    pub fn instance(&self) -> &PoapInstance<P> {
+        &self.contract
+    }
+
+    async fn send<C: SolCall>(
+        &self,
+        call: SolCallBuilder<&P, C>,
+    ) -> Result<TransactionReceipt, ClientError> {
+        let receipt = call.send().await?.get_receipt().await?;
+        if !receipt.status() {
+            return Err(ClientError::Reverted(receipt.transaction_hash));
+        }
+        Ok(receipt)
+    }
+
+    /// Sends a mint transaction and returns the id of the minted token.
+    async fn send_mint<C: SolCall>(
+        &self,
+        call: SolCallBuilder<&P, C>,
+    ) -> Result<U256, ClientError> {
+        let receipt = self.send(call).await?;
+        minted_token_ids(self.address(), &receipt)
+            .first()
+            .copied()
+            .ok_or(ClientError::MissingTransfer(receipt.transaction_hash))
+    }
+
+    /// Sends a transaction that creates something and returns the id read
+    /// from the first `E` log the contract emitted.
+    async fn send_create<C: SolCall, E: SolEvent>(
+        &self,
+        call: SolCallBuilder<&P, C>,
+        id: impl Fn(E) -> U256,
+    ) -> Result<U256, ClientError> {
+        let receipt = self.send(call).await?;
+        receipt
+            .inner
+            .logs()
+            .iter()
+            .filter(|log| log.address() == self.address())
+            .find_map(|log| E::decode_log_data(log.data()).ok())
+            .map(id)
+            .ok_or(ClientError::MissingLog(
+                E::SIGNATURE,
+                receipt.transaction_hash,
+            ))
+    }
+
+    // ============ EVENT MANAGEMENT ============
+
+    /// Creates an event and returns its id.
+    pub async fn create_event(&self, name: &str, organizer: Address) -> Result<U256, ClientError> {
+        self.send_create(
+            self.contract.createEvent(name.to_owned(), organizer),
+            |event: Poap::EventCreated| event.eventId,
+        )
+        .await
+    }
+
+    /// Creates an event whose badge requires `session_threshold` session
+    /// check-ins and returns its id.
+    pub async fn create_session_event(
+        &self,
+        name: &str,
+        organizer: Address,
+        session_threshold: U256,
+        auto_mint: bool,
+    ) -> Result<U256, ClientError> {
+        self.send_create(
+            self.contract.createSessionEvent(
+                name.to_owned(),
+                organizer,
+                session_threshold,
+                auto_mint,
+            ),
+            |event: Poap::EventCreated| event.eventId,
+        )
+        .await
+    }
+
+    /// Adds a session to `event_id` and returns its id.
+    pub async fn add_event_session(&self, event_id: U256, name: &str) -> Result<U256, ClientError> {
+        self.send_create(
+            self.contract.addEventSession(event_id, name.to_owned()),
+            |session: Poap::SessionAdded| session.sessionId,
+        )
+        .await
+    }
+
+    /// Checks `attendee` in to `session_id`. Returns the id of the badge the
+    /// check-in minted, if it met the threshold of an auto-mint event.
+    pub async fn check_in(
+        &self,
+        event_id: U256,
+        session_id: U256,
+        attendee: Address,
+    ) -> Result<Option<U256>, ClientError> {
+        let receipt = self
+            .send(self.contract.checkIn(event_id, session_id, attendee))
+            .await?;
+        Ok(minted_token_ids(self.address(), &receipt).first().copied())
+    }
+
+    /// Claims the sender's badge of a session event and returns its id.
+    pub async fn claim_event_badge(&self, event_id: U256) -> Result<U256, ClientError> {
+        self.send_mint(self.contract.claimEventBadge(event_id))
+            .await
+    }
+
+    /// Sets the root of `event_id`'s Merkle allowlist, as built by
+    /// `poap-allowlist`.
+    pub async fn set_event_allowlist_root(
+        &self,
+        event_id: U256,
+        root: B256,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.setEventAllowlistRoot(event_id, root))
+            .await
+    }
+
+    /// Claims the sender's badge of `event_id` with its allowlist proof and
+    /// returns the new token id.
+    pub async fn claim_with_proof(
+        &self,
+        event_id: U256,
+        proof: Vec<B256>,
+    ) -> Result<U256, ClientError> {
+        self.send_mint(self.contract.claimWithProof(event_id, proof))
+            .await
+    }
+
+    pub async fn add_event_minter(
+        &self,
+        event_id: U256,
+        minter: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.addEventMinter(event_id, minter))
+            .await
+    }
+
+    /// Registers `hook` to be called after every mint of `event_id`. The zero
+    /// address removes the hook.
+    pub async fn set_event_mint_hook(
+        &self,
+        event_id: U256,
+        hook: Address,
+        revert_on_failure: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(
+            self.contract
+                .setEventMintHook(event_id, hook, revert_on_failure),
+        )
+        .await
+    }
+
+    /// Adds a badge role to `event_id` and returns its id.
+    pub async fn add_event_role(
+        &self,
+        event_id: U256,
+        name: &str,
+        supply_cap: U256,
+    ) -> Result<U256, ClientError> {
+        self.send_create(
+            self.contract
+                .addEventRole(event_id, name.to_owned(), supply_cap),
+            |role: Poap::EventRoleAdded| role.roleId,
+        )
+        .await
+    }
+
+    pub async fn set_role_minter(
+        &self,
+        event_id: U256,
+        role_id: U256,
+        minter: Address,
+        allowed: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(
+            self.contract
+                .setRoleMinter(event_id, role_id, minter, allowed),
+        )
+        .await
+    }
+
+    /// Mints a badge for `event_id` to `to` and returns the new token id.
+    pub async fn mint_token(&self, event_id: U256, to: Address) -> Result<U256, ClientError> {
+        self.send_mint(self.contract.mintToken(event_id, to)).await
+    }
+
+    /// Mints a badge of `role_id` for `event_id` to `to` and returns the new
+    /// token id.
+    pub async fn mint_token_with_role(
+        &self,
+        event_id: U256,
+        to: Address,
+        role_id: U256,
+    ) -> Result<U256, ClientError> {
+        self.send_mint(self.contract.mintTokenWithRole(event_id, to, role_id))
+            .await
+    }
+
+    /// Like [`Self::mint_token`], but checks `onERC721Received` on contract
+    /// recipients and forwards `data` to it.
+    pub async fn mint_token_safe(
+        &self,
+        event_id: U256,
+        to: Address,
+        data: Bytes,
+    ) -> Result<U256, ClientError> {
+        self.send_mint(self.contract.mintTokenSafe(event_id, to, data))
+            .await
+    }
+
+    /// Mints a badge for `event_id` to every recipient.
+    ///
+    /// Transactions are sent `chunk_size` at a time and each chunk's receipts
+    /// are awaited before the next one is sent. The result for each recipient
+    /// is returned in input order; a failed mint does not stop the others.
+    pub async fn mint_to_many(
+        &self,
+        event_id: U256,
+        recipients: &[Address],
+        chunk_size: usize,
+    ) -> Vec<Result<U256, ClientError>> {
+        let mut results = Vec::with_capacity(recipients.len());
+        for chunk in recipients.chunks(chunk_size.max(1)) {
+            let mut pending = Vec::with_capacity(chunk.len());
+            for &recipient in chunk {
+                pending.push(self.contract.mintToken(event_id, recipient).send().await);
+            }
+            for sent in pending {
+                let result = match sent {
+                    Ok(tx) => match tx.get_receipt().await {
+                        Ok(receipt) if receipt.status() => {
+                            minted_token_ids(self.address(), &receipt)
+                                .first()
+                                .copied()
+                                .ok_or(ClientError::MissingTransfer(receipt.transaction_hash))
+                        }
+                        Ok(receipt) => Err(ClientError::Reverted(receipt.transaction_hash)),
+                        Err(err) => Err(err.into()),
+                    },
+                    Err(err) => Err(err.into()),
+                };
+                results.push(result);
+            }
+        }
+        results
+    }
+
+    /// Burns a badge, clearing its holder's attendance.
+    pub async fn revoke_badge(&self, token_id: U256) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.revokeBadge(token_id)).await
+    }
+
+    // ============ ACHIEVEMENTS ============
+
+    /// Creates an achievement and returns its id.
+    pub async fn create_achievement(
+        &self,
+        name: &str,
+        event_ids: Vec<U256>,
+        threshold: U256,
+    ) -> Result<U256, ClientError> {
+        self.send_create(
+            self.contract
+                .createAchievement(name.to_owned(), event_ids, threshold),
+            |achievement: Poap::AchievementCreated| achievement.achievementId,
+        )
+        .await
+    }
+
+    pub async fn set_achievement_active(
+        &self,
+        achievement_id: U256,
+        active: bool,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.setAchievementActive(achievement_id, active))
+            .await
+    }
+
+    /// Claims an achievement for the sender and returns the new token id.
+    pub async fn claim_achievement(&self, achievement_id: U256) -> Result<U256, ClientError> {
+        self.send_mint(self.contract.claimAchievement(achievement_id))
+            .await
+    }
+
+    // ============ ADMIN ============
+
+    /// Initializes a freshly deployed contract. Can only be called once.
+    pub async fn initialize(
+        &self,
+        name: &str,
+        symbol: &str,
+        base_uri: &str,
+        owner: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.initialize(
+            name.to_owned(),
+            symbol.to_owned(),
+            base_uri.to_owned(),
+            owner,
+        ))
+        .await
+    }
+
+    pub async fn transfer_ownership(
+        &self,
+        new_owner: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.transferOwnership(new_owner)).await
+    }
+
+    pub async fn owner(&self) -> Result<Address, ClientError> {
+        Ok(self.contract.getOwner().call().await?)
+    }
+
+    // ============ META-TRANSACTIONS ============
+
+    /// Sets the ERC-2771 forwarder allowed to relay signed calls. The zero
+    /// address disables relaying.
+    pub async fn set_trusted_forwarder(
+        &self,
+        forwarder: Address,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.setTrustedForwarder(forwarder))
+            .await
+    }
+
+    pub async fn trusted_forwarder(&self) -> Result<Address, ClientError> {
+        Ok(self.contract.getTrustedForwarder().call().await?)
+    }
+
+    // ============ UPGRADES ============
+
+    /// Points the proxy at `new_implementation` and, if `data` is not empty,
+    /// delegate-calls it with `data`.
+    pub async fn upgrade_to_and_call(
+        &self,
+        new_implementation: Address,
+        data: Bytes,
+    ) -> Result<TransactionReceipt, ClientError> {
+        self.send(self.contract.upgradeToAndCall(new_implementation, data))
+            .await
+    }
+
+    /// Returns the implementation the proxy delegates to.
+    pub async fn implementation(&self) -> Result<Address, ClientError> {
+        Ok(self.contract.getImplementation().call().await?)
+    }
+
+    // ============ ERC-721 READS ============
+
+    pub async fn name(&self) -> Result<String, ClientError> {
+        Ok(self.contract.name().call().await?)
+    }
+
+    pub async fn symbol(&self) -> Result<String, ClientError> {
+        Ok(self.contract.symbol().call().await?)
+    }
+
+    pub async fn balance_of(&self, owner: Address) -> Result<U256, ClientError> {
+        Ok(self.contract.balanceOf(owner).call().await?)
+    }
+
+    pub async fn owner_of(&self, token_id: U256) -> Result<Address, ClientError> {
+        Ok(self.contract.ownerOf(token_id).call().await?)
+    }
+
+    pub async fn token_uri(&self, token_id: U256) -> Result<String, ClientError> {
+        Ok(self.contract.tokenURI(token_id).call().await?)
+    }
+
+    pub async fn total_supply(&self) -> Result<U256, ClientError> {
+        Ok(self.contract.totalSupply().call().await?)
+    }
+
+    pub async fn token_of_owner_by_index(
+        &self,
+        owner: Address,
+        index: U256,
+    ) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .tokenOfOwnerByIndex(owner, index)
+            .call()
+            .await?)
+    }
+
+    pub async fn token_by_index(&self, index: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.tokenByIndex(index).call().await?)
+    }
+
+    pub async fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .supportsInterface(interface_id.into())
+            .call()
+            .await?)
+    }
+
+    // ============ EVENT VIEWS ============
+
+    pub async fn last_event_id(&self) -> Result<U256, ClientError> {
+        Ok(self.contract.getLastEventId().call().await?)
+    }
+
+    pub async fn last_token_id(&self) -> Result<U256, ClientError> {
+        Ok(self.contract.getLastTokenId().call().await?)
+    }
+
+    pub async fn token_info(&self, token_id: U256) -> Result<TokenInfo, ClientError> {
+        let info = self.contract.getTokenInfo(token_id).call().await?;
+        Ok((info._0, info._1, info._2, info._3).into())
+    }
+
+    pub async fn event(&self, event_id: U256) -> Result<EventInfo, ClientError> {
+        let event = self.contract.getEvent(event_id).call().await?;
+        Ok((event._0, event._1, event._2, event._3, event._4).into())
+    }
+
+    pub async fn events(&self, offset: U256, limit: U256) -> Result<Vec<EventInfo>, ClientError> {
+        let events = self.contract.getEvents(offset, limit).call().await?;
+        Ok(events.into_iter().map(EventInfo::from).collect())
+    }
+
+    pub async fn organizer_event_count(&self, organizer: Address) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .getOrganizerEventCount(organizer)
+            .call()
+            .await?)
+    }
+
+    pub async fn events_by_organizer(
+        &self,
+        organizer: Address,
+        offset: U256,
+        limit: U256,
+    ) -> Result<Vec<EventInfo>, ClientError> {
+        let events = self
+            .contract
+            .getEventsByOrganizer(organizer, offset, limit)
+            .call()
+            .await?;
+        Ok(events.into_iter().map(EventInfo::from).collect())
+    }
+
+    pub async fn is_event_minter(
+        &self,
+        event_id: U256,
+        minter: Address,
+    ) -> Result<bool, ClientError> {
+        Ok(self.contract.isEventMinter(event_id, minter).call().await?)
+    }
+
+    pub async fn event_role_count(&self, event_id: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.getEventRoleCount(event_id).call().await?)
+    }
+
+    pub async fn event_role(
+        &self,
+        event_id: U256,
+        role_id: U256,
+    ) -> Result<EventRole, ClientError> {
+        let role = self.contract.getEventRole(event_id, role_id).call().await?;
+        Ok((role._0, role._1, role._2, role._3).into())
+    }
+
+    pub async fn is_role_minter(
+        &self,
+        event_id: U256,
+        role_id: U256,
+        minter: Address,
+    ) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .isRoleMinter(event_id, role_id, minter)
+            .call()
+            .await?)
+    }
+
+    pub async fn token_role(&self, token_id: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.getTokenRole(token_id).call().await?)
+    }
+
+    pub async fn event_session_threshold(&self, event_id: U256) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .getEventSessionThreshold(event_id)
+            .call()
+            .await?)
+    }
+
+    pub async fn is_event_auto_mint(&self, event_id: U256) -> Result<bool, ClientError> {
+        Ok(self.contract.isEventAutoMint(event_id).call().await?)
+    }
+
+    pub async fn event_session_count(&self, event_id: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.getEventSessionCount(event_id).call().await?)
+    }
+
+    pub async fn event_session_name(
+        &self,
+        event_id: U256,
+        session_id: U256,
+    ) -> Result<String, ClientError> {
+        Ok(self
+            .contract
+            .getEventSessionName(event_id, session_id)
+            .call()
+            .await?)
+    }
+
+    pub async fn has_checked_in(
+        &self,
+        event_id: U256,
+        session_id: U256,
+        attendee: Address,
+    ) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .hasCheckedIn(event_id, session_id, attendee)
+            .call()
+            .await?)
+    }
+
+    pub async fn attended_session_count(
+        &self,
+        event_id: U256,
+        attendee: Address,
+    ) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .getAttendedSessionCount(event_id, attendee)
+            .call()
+            .await?)
+    }
+
+    pub async fn event_mint_hook(&self, event_id: U256) -> Result<Address, ClientError> {
+        Ok(self.contract.getEventMintHook(event_id).call().await?)
+    }
+
+    pub async fn is_event_mint_hook_required(&self, event_id: U256) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .isEventMintHookRequired(event_id)
+            .call()
+            .await?)
+    }
+
+    pub async fn token_event(&self, token_id: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.getTokenEvent(token_id).call().await?)
+    }
+
+    pub async fn event_attendee_count(&self, event_id: U256) -> Result<U256, ClientError> {
+        Ok(self.contract.getEventAttendeeCount(event_id).call().await?)
+    }
+
+    /// Returns the length of the list [`Self::event_attendees`] pages over,
+    /// revoked entries included.
+    pub async fn event_attendee_list_length(&self, event_id: U256) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .getEventAttendeeListLength(event_id)
+            .call()
+            .await?)
+    }
+
+    /// Returns the live attendees in `offset..offset + limit` of the event's
+    /// append-only attendee list. Revoked entries are skipped, so a page can
+    /// be shorter than `limit`.
+    pub async fn event_attendees(
+        &self,
+        event_id: U256,
+        offset: U256,
+        limit: U256,
+    ) -> Result<Vec<Address>, ClientError> {
+        Ok(self
+            .contract
+            .getEventAttendees(event_id, offset, limit)
+            .call()
+            .await?)
+    }
+
+    pub async fn holder_badges(
+        &self,
+        owner: Address,
+        offset: U256,
+        limit: U256,
+    ) -> Result<Vec<HolderBadge>, ClientError> {
+        let badges = self
+            .contract
+            .getHolderBadges(owner, offset, limit)
+            .call()
+            .await?;
+        Ok(badges.into_iter().map(HolderBadge::from).collect())
+    }
+
+    // ============ ATTENDANCE VIEWS ============
+
+    pub async fn has_attended(
+        &self,
+        event_id: U256,
+        account: Address,
+    ) -> Result<bool, ClientError> {
+        Ok(self.contract.hasAttended(event_id, account).call().await?)
+    }
+
+    pub async fn has_attended_any(
+        &self,
+        event_ids: Vec<U256>,
+        account: Address,
+    ) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .hasAttendedAny(event_ids, account)
+            .call()
+            .await?)
+    }
+
+    pub async fn attended_count(&self, account: Address) -> Result<U256, ClientError> {
+        Ok(self.contract.attendedCount(account).call().await?)
+    }
+
+    // ============ ACHIEVEMENT VIEWS ============
+
+    pub async fn last_achievement_id(&self) -> Result<U256, ClientError> {
+        Ok(self.contract.getLastAchievementId().call().await?)
+    }
+
+    pub async fn achievement_name(&self, achievement_id: U256) -> Result<String, ClientError> {
+        Ok(self
+            .contract
+            .getAchievementName(achievement_id)
+            .call()
+            .await?)
+    }
+
+    pub async fn achievement_events(&self, achievement_id: U256) -> Result<Vec<U256>, ClientError> {
+        Ok(self
+            .contract
+            .getAchievementEvents(achievement_id)
+            .call()
+            .await?)
+    }
+
+    pub async fn achievement_threshold(&self, achievement_id: U256) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .getAchievementThreshold(achievement_id)
+            .call()
+            .await?)
+    }
+
+    pub async fn is_achievement_active(&self, achievement_id: U256) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .isAchievementActive(achievement_id)
+            .call()
+            .await?)
+    }
+
+    pub async fn has_claimed_achievement(
+        &self,
+        achievement_id: U256,
+        account: Address,
+    ) -> Result<bool, ClientError> {
+        Ok(self
+            .contract
+            .hasClaimedAchievement(achievement_id, account)
+            .call()
+            .await?)
+    }
+
+    pub async fn achievement_progress(
+        &self,
+        achievement_id: U256,
+        account: Address,
+    ) -> Result<U256, ClientError> {
+        Ok(self
+            .contract
+            .getAchievementProgress(achievement_id, account)
+            .call()
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        contract,
+        primitives::{address, b256, keccak256, Log as PrimitiveLog, U256},
+        rpc::json_rpc::ErrorPayload,
+        sol_types::{SolError, SolEvent},
+        transports::RpcError,
+    };
+
+    use super::*;
+
+    fn revert_error(data: Vec<u8>) -> contract::Error {
+        let data = format!("\"{}\"", Bytes::from(data));
+        contract::Error::TransportError(RpcError::ErrorResp(ErrorPayload {
+            code: 3,
+            message: "execution reverted".into(),
+            data: Some(serde_json::value::RawValue::from_string(data).unwrap()),
+        }))
+    }
+
+    #[test]
+    fn proxy_uses_erc1967_implementation_slot() {
+        let slot = U256::from_be_bytes(keccak256("eip1967.proxy.implementation").0) - U256::from(1);
+        let code = proxy_bytecode();
+        let count = |needle: &[u8]| {
+            code.windows(needle.len())
+                .filter(|window| *window == needle)
+                .count()
+        };
+
+        assert_eq!(count(&slot.to_be_bytes::<32>()), 2);
+        assert_eq!(count(Erc1967Proxy::Upgraded::SIGNATURE_HASH.as_slice()), 1);
+    }
+
+    #[test]
+    fn deploy_script_uses_shared_proxy_bytecode() {
+        let script = include_str!("../../scripts/deploy_proxy.ts");
+
+        assert!(script.contains(r#""erc1967_proxy.hex""#));
+        assert!(!script.contains(&ERC1967_PROXY_BYTECODE.trim()[..32]));
+    }
+
+    #[test]
+    fn decodes_poap_reverts() {
+        let revert = Poap::EventNotFound {
+            eventId: U256::from(7),
+        };
+        let err = ClientError::from(revert_error(revert.abi_encode()));
+
+        let Some(PoapErrors::EventNotFound(decoded)) = err.as_revert() else {
+            panic!("expected EventNotFound, got {err:?}");
+        };
+        assert_eq!(decoded.eventId, U256::from(7));
+    }
+
+    #[test]
+    fn keeps_undecodable_errors() {
+        let err = ClientError::from(revert_error(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert!(matches!(err, ClientError::Contract(_)));
+        assert!(err.as_revert().is_none());
+    }
+
+    #[test]
+    fn decodes_transfer_logs() {
+        let contract = address!("0x0000000000000000000000000000000000000001");
+        let transfer = Poap::Transfer {
+            from: Address::ZERO,
+            to: address!("0x0000000000000000000000000000000000000002"),
+            tokenId: U256::from(3),
+        };
+        let log = Log {
+            inner: PrimitiveLog {
+                address: contract,
+                data: transfer.encode_log_data(),
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(decode_log(&log), Some(PoapEvents::Transfer(transfer)));
+    }
+
+    #[test]
+    fn attendance_interface_id_matches_selectors() {
+        let id = [
+            Poap::hasAttendedCall::SELECTOR,
+            Poap::hasAttendedAnyCall::SELECTOR,
+            Poap::attendedCountCall::SELECTOR,
+        ]
+        .into_iter()
+        .fold([0; 4], |id, selector| {
+            std::array::from_fn(|i| id[i] ^ selector[i])
+        });
+
+        assert_eq!(id, ATTENDANCE_INTERFACE_ID);
+    }
+
+    #[test]
+    fn ignores_foreign_logs() {
+        let log = Log {
+            inner: PrimitiveLog::new_unchecked(
+                Address::ZERO,
+                vec![b256!(
+                    "0x0000000000000000000000000000000000000000000000000000000000000001"
+                )],
+                Bytes::new(),
+            ),
+            ..Default::default()
+        };
+
+        assert_eq!(decode_log(&log), None);
+    }
+}
@@ -0,0 +1,396 @@
+//! `poap`: command-line tool for organizers to manage events and mint badges.
+//!
+//! The contract address, RPC URL and signer are read from flags or the
+//! `POAP_ADDRESS`, `RPC_URL`, `PRIVATE_KEY`, `KEYSTORE` and
+//! `KEYSTORE_PASSWORD` environment variables. Pass `--dry-run` to simulate
+//! writes with `eth_call` instead of sending transactions.
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use alloy::{
+    contract::SolCallBuilder,
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    sol_types::SolCall,
+};
+use clap::{Args, Parser, Subcommand};
+use eyre::{bail, eyre, Result, WrapErr};
+use poap_client::{ClientError, PoapClient, DEFAULT_MINT_CHUNK_SIZE};
+
+const DEVNODE_RPC_URL: &str = "http://127.0.0.1:8547";
+
+#[derive(Parser)]
+#[command(name = "poap", about = "Manage Poap events and mint badges")]
+struct Cli {
+    /// Address of the deployed Poap contract.
+    #[arg(long, env = "POAP_ADDRESS", global = true)]
+    contract: Option<Address>,
+
+    /// JSON-RPC endpoint of the chain.
+    #[arg(long, env = "RPC_URL", default_value = DEVNODE_RPC_URL, global = true)]
+    rpc_url: String,
+
+    #[command(flatten)]
+    signer: SignerArgs,
+
+    /// Simulate writes with `eth_call` and print the outcome without sending.
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct SignerArgs {
+    /// Hex-encoded private key of the sender.
+    #[arg(long, env = "PRIVATE_KEY", global = true, hide_env_values = true)]
+    private_key: Option<String>,
+
+    /// Encrypted JSON keystore of the sender, used instead of a private key.
+    #[arg(long, env = "KEYSTORE", global = true, conflicts_with = "private_key")]
+    keystore: Option<PathBuf>,
+
+    /// Password of `--keystore`.
+    #[arg(long, env = "KEYSTORE_PASSWORD", global = true, hide_env_values = true)]
+    keystore_password: Option<String>,
+}
+
+impl SignerArgs {
+    fn signer(&self) -> Result<PrivateKeySigner> {
+        match (&self.private_key, &self.keystore) {
+            (Some(key), _) => key.parse().wrap_err("invalid private key"),
+            (None, Some(path)) => {
+                let password = self
+                    .keystore_password
+                    .as_deref()
+                    .ok_or_else(|| eyre!("--keystore requires KEYSTORE_PASSWORD"))?;
+                PrivateKeySigner::decrypt_keystore(path, password)
+                    .wrap_err_with(|| format!("cannot decrypt keystore {}", path.display()))
+            }
+            (None, None) => bail!("set PRIVATE_KEY or KEYSTORE to sign transactions"),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create an event. Only the contract owner can do this.
+    CreateEvent {
+        name: String,
+        /// Organizer of the event, allowed to mint its badges.
+        organizer: Address,
+    },
+    /// Allow an account to mint badges for an event.
+    AddMinter { event_id: U256, minter: Address },
+    /// Mint a badge for an event to a single recipient.
+    Mint { event_id: U256, to: Address },
+    /// Mint a badge for an event to every address listed in a CSV file.
+    ///
+    /// The file's `address` column is used, or its first column if there is
+    /// no such header.
+    MintCsv {
+        event_id: U256,
+        file: PathBuf,
+        /// Number of transactions sent before waiting for their receipts.
+        #[arg(long, default_value_t = DEFAULT_MINT_CHUNK_SIZE)]
+        chunk_size: usize,
+    },
+    /// Show event details.
+    Event {
+        #[command(subcommand)]
+        command: EventCommand,
+    },
+    /// Show holder details.
+    Holder {
+        #[command(subcommand)]
+        command: HolderCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum EventCommand {
+    /// Print an event and its attendees.
+    Show { event_id: U256 },
+}
+
+#[derive(Subcommand)]
+enum HolderCommand {
+    /// Print the badges held by an account.
+    Show { account: Address },
+}
+
+/// Page size used when listing attendees and badges.
+const PAGE_SIZE: u64 = 100;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let contract = cli
+        .contract
+        .ok_or_else(|| eyre!("set --contract or POAP_ADDRESS"))?;
+    let rpc_url = cli.rpc_url.parse().wrap_err("invalid RPC URL")?;
+
+    match cli.command {
+        Command::Event { command } => {
+            let client = PoapClient::new(contract, ProviderBuilder::new().connect_http(rpc_url));
+            match command {
+                EventCommand::Show { event_id } => show_event(&client, event_id).await,
+            }
+        }
+        Command::Holder { command } => {
+            let client = PoapClient::new(contract, ProviderBuilder::new().connect_http(rpc_url));
+            match command {
+                HolderCommand::Show { account } => show_holder(&client, account).await,
+            }
+        }
+        command => {
+            let provider = ProviderBuilder::new()
+                .wallet(cli.signer.signer()?)
+                .connect_http(rpc_url);
+            let client = PoapClient::new(contract, provider);
+            if cli.dry_run {
+                simulate(&client, command).await
+            } else {
+                execute(&client, command).await
+            }
+        }
+    }
+}
+
+async fn execute<P: Provider>(client: &PoapClient<P>, command: Command) -> Result<()> {
+    match command {
+        Command::CreateEvent { name, organizer } => {
+            let event_id = client
+                .create_event(&name, organizer)
+                .await
+                .map_err(report)?;
+            println!("created event {event_id}");
+        }
+        Command::AddMinter { event_id, minter } => {
+            let receipt = client
+                .add_event_minter(event_id, minter)
+                .await
+                .map_err(report)?;
+            println!("added minter {minter} in {}", receipt.transaction_hash);
+        }
+        Command::Mint { event_id, to } => {
+            let token_id = client.mint_token(event_id, to).await.map_err(report)?;
+            println!("minted token {token_id} to {to}");
+        }
+        Command::MintCsv {
+            event_id,
+            file,
+            chunk_size,
+        } => {
+            let recipients = read_recipients(&file)?;
+            let results = client.mint_to_many(event_id, &recipients, chunk_size).await;
+            let mut failed = 0;
+            for (recipient, result) in recipients.iter().zip(results) {
+                match result {
+                    Ok(token_id) => println!("{recipient}: minted token {token_id}"),
+                    Err(err) => {
+                        failed += 1;
+                        println!("{recipient}: {}", describe(&err));
+                    }
+                }
+            }
+            if failed > 0 {
+                bail!("{failed} of {} mints failed", recipients.len());
+            }
+        }
+        Command::Event { .. } | Command::Holder { .. } => unreachable!("read-only commands"),
+    }
+    Ok(())
+}
+
+async fn simulate<P: Provider>(client: &PoapClient<P>, command: Command) -> Result<()> {
+    let poap = client.instance();
+    match command {
+        Command::CreateEvent { name, organizer } => {
+            let event_id = dry_run(poap.createEvent(name, organizer)).await?;
+            println!("would create event {event_id}");
+        }
+        Command::AddMinter { event_id, minter } => {
+            dry_run(poap.addEventMinter(event_id, minter)).await?;
+            println!("would add minter {minter} to event {event_id}");
+        }
+        Command::Mint { event_id, to } => {
+            let token_id = dry_run(poap.mintToken(event_id, to)).await?;
+            println!("would mint token {token_id} to {to}");
+        }
+        Command::MintCsv { event_id, file, .. } => {
+            let recipients = read_recipients(&file)?;
+            let mut seen = HashSet::new();
+            let mut failed = 0;
+            // Each mint is simulated against the current state, so duplicates
+            // within the file are only caught here.
+            for recipient in &recipients {
+                if !seen.insert(*recipient) {
+                    failed += 1;
+                    println!("{recipient}: listed more than once");
+                    continue;
+                }
+                match poap.mintToken(event_id, *recipient).call().await {
+                    Ok(_) => println!("{recipient}: ok"),
+                    Err(err) => {
+                        failed += 1;
+                        println!("{recipient}: {}", describe(&err.into()));
+                    }
+                }
+            }
+            if failed > 0 {
+                bail!("{failed} of {} mints would fail", recipients.len());
+            }
+        }
+        Command::Event { .. } | Command::Holder { .. } => unreachable!("read-only commands"),
+    }
+    Ok(())
+}
+
+/// Simulates `call` with `eth_call`, decoding a revert into a `PoapError`.
+async fn dry_run<P: Provider, C: SolCall>(call: SolCallBuilder<&P, C>) -> Result<C::Return> {
+    call.call()
+        .await
+        .map_err(|err| report(ClientError::from(err)))
+}
+
+async fn show_event<P: Provider>(client: &PoapClient<P>, event_id: U256) -> Result<()> {
+    let event = client.event(event_id).await.map_err(report)?;
+    println!("event      {}", event.id);
+    println!("name       {}", event.name);
+    println!("organizer  {}", event.organizer);
+    println!("active     {}", event.active);
+    println!("attendees  {}", event.attendee_count);
+
+    let length = client
+        .event_attendee_list_length(event_id)
+        .await
+        .map_err(report)?;
+    let mut offset = U256::ZERO;
+    while offset < length {
+        let attendees = client
+            .event_attendees(event_id, offset, U256::from(PAGE_SIZE))
+            .await
+            .map_err(report)?;
+        for attendee in &attendees {
+            println!("  {attendee}");
+        }
+        offset += U256::from(PAGE_SIZE);
+    }
+    Ok(())
+}
+
+async fn show_holder<P: Provider>(client: &PoapClient<P>, account: Address) -> Result<()> {
+    let balance = client.balance_of(account).await.map_err(report)?;
+    println!("holder  {account}");
+    println!("badges  {balance}");
+
+    let mut offset = U256::ZERO;
+    while offset < balance {
+        let badges = client
+            .holder_badges(account, offset, U256::from(PAGE_SIZE))
+            .await
+            .map_err(report)?;
+        for badge in &badges {
+            println!(
+                "  token {} event {} ({})",
+                badge.token_id, badge.event_id, badge.event_name
+            );
+        }
+        offset += U256::from(PAGE_SIZE);
+    }
+    Ok(())
+}
+
+/// Reads recipient addresses from a CSV file.
+fn read_recipients(path: &Path) -> Result<Vec<Address>> {
+    let file = File::open(path).wrap_err_with(|| format!("cannot open {}", path.display()))?;
+    parse_recipients(file)
+}
+
+fn parse_recipients(reader: impl Read) -> Result<Vec<Address>> {
+    let mut csv = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+    let mut records = csv.records().peekable();
+
+    // Use the `address` column of a header row, or the first column if the
+    // file has no header.
+    let mut column = 0;
+    if let Some(Ok(first)) = records.peek() {
+        if first
+            .get(0)
+            .is_some_and(|field| field.parse::<Address>().is_err())
+        {
+            column = first
+                .iter()
+                .position(|field| field.eq_ignore_ascii_case("address"))
+                .ok_or_else(|| eyre!("header has no `address` column"))?;
+            records.next();
+        }
+    }
+
+    let mut recipients = Vec::new();
+    for record in records {
+        let record = record?;
+        let line = record.position().map_or(0, |position| position.line());
+        let field = record
+            .get(column)
+            .ok_or_else(|| eyre!("line {line}: missing address"))?;
+        if field.is_empty() {
+            continue;
+        }
+        let address = field
+            .parse()
+            .wrap_err_with(|| format!("line {line}: invalid address `{field}`"))?;
+        recipients.push(address);
+    }
+    Ok(recipients)
+}
+
+fn describe(err: &ClientError) -> String {
+    match err.as_revert() {
+        Some(revert) => format!("reverted with {revert:?}"),
+        None => err.to_string(),
+    }
+}
+
+fn report(err: ClientError) -> eyre::Report {
+    eyre!(describe(&err))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    const ALICE: Address = address!("0x00000000000000000000000000000000000000a1");
+    const BOB: Address = address!("0x00000000000000000000000000000000000000b0");
+
+    #[test]
+    fn parses_headerless_csv() {
+        let csv = format!("{ALICE}\n\n{BOB}\n");
+        assert_eq!(parse_recipients(csv.as_bytes()).unwrap(), vec![ALICE, BOB]);
+    }
+
+    #[test]
+    fn parses_address_column() {
+        let csv = format!("name,address\nalice,{ALICE}\nbob, {BOB}\n");
+        assert_eq!(parse_recipients(csv.as_bytes()).unwrap(), vec![ALICE, BOB]);
+    }
+
+    #[test]
+    fn rejects_invalid_addresses() {
+        let csv = format!("address\n{ALICE}\nnot-an-address\n");
+        let err = parse_recipients(csv.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{err}");
+    }
+}
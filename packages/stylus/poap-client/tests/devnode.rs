@@ -0,0 +1,245 @@
+//! Integration tests against a running nitro devnode.
+//!
+//! Deploy `Poap` (e.g. with `yarn deploy`) and run with:
+//!
+//! ```sh
+//! POAP_ADDRESS=0x... cargo test --features e2e
+//! ```
+//!
+//! `RPC_URL` and `PRIVATE_KEY` default to the devnode's endpoint and
+//! pre-funded account. The signer must own the deployed contract.
+//! `POAP_FACTORY_ADDRESS` is read by the factory tests only.
+#![cfg(feature = "e2e")]
+
+use alloy::{
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    sol_types::SolEvent,
+};
+use eyre::Result;
+use poap_client::{abi::PoapFactory, factory, PoapClient, PoapErrors, DEFAULT_MINT_CHUNK_SIZE};
+
+const DEVNODE_RPC_URL: &str = "http://127.0.0.1:8547";
+const DEVNODE_PRIVATE_KEY: &str =
+    "0xb6b15c8cb491557369f3c7d2c287b053eb229daa9c22138887752191c9520659";
+
+fn client() -> Result<PoapClient<impl Provider>> {
+    let rpc_url = std::env::var("RPC_URL").unwrap_or_else(|_| DEVNODE_RPC_URL.to_owned());
+    let key = std::env::var("PRIVATE_KEY").unwrap_or_else(|_| DEVNODE_PRIVATE_KEY.to_owned());
+    let address: Address = std::env::var("POAP_ADDRESS")?.parse()?;
+    let signer: PrivateKeySigner = key.parse()?;
+    let provider = ProviderBuilder::new()
+        .wallet(signer)
+        .connect_http(rpc_url.parse()?);
+    Ok(PoapClient::new(address, provider))
+}
+
+fn factory_address() -> Result<Address> {
+    Ok(std::env::var("POAP_FACTORY_ADDRESS")?.parse()?)
+}
+
+#[tokio::test]
+async fn creates_event_and_mints() -> Result<()> {
+    let client = client()?;
+    let organizer = client.owner().await?;
+
+    let event_id = client.create_event("Client Test", organizer).await?;
+    let event = client.event(event_id).await?;
+    assert_eq!(event.name, "Client Test");
+    assert_eq!(event.organizer, organizer);
+    assert!(event.active);
+
+    let alice = Address::repeat_byte(0xa1);
+    let token_id = client.mint_token(event_id, alice).await?;
+    assert_eq!(client.owner_of(token_id).await?, alice);
+    assert_eq!(client.token_event(token_id).await?, event_id);
+
+    let badges = client
+        .holder_badges(alice, U256::ZERO, U256::from(10))
+        .await?;
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges[0].token_id, token_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn decodes_reverts() -> Result<()> {
+    let client = client()?;
+    let missing = client.last_event_id().await? + U256::from(1);
+
+    let err = client
+        .mint_token(missing, Address::repeat_byte(0xa1))
+        .await
+        .expect_err("should not mint for a missing event");
+    assert!(
+        matches!(err.as_revert(), Some(PoapErrors::EventNotFound(e)) if e.eventId == missing),
+        "{err:?}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn mints_to_many() -> Result<()> {
+    let client = client()?;
+    let organizer = client.owner().await?;
+    let event_id = client.create_event("Batch Test", organizer).await?;
+
+    let mut recipients: Vec<Address> = (1..=5).map(Address::with_last_byte).collect();
+    // A duplicate recipient fails without stopping the rest of the batch.
+    recipients.push(recipients[0]);
+
+    let results = client
+        .mint_to_many(event_id, &recipients, DEFAULT_MINT_CHUNK_SIZE)
+        .await;
+    assert_eq!(results.len(), recipients.len());
+    assert!(results[..5].iter().all(Result::is_ok));
+    assert!(results[5].is_err());
+    assert_eq!(client.event_attendee_count(event_id).await?, U256::from(5));
+    Ok(())
+}
+
+#[tokio::test]
+async fn factory_deploys_to_predicted_address() -> Result<()> {
+    let client = client()?;
+    let factory_address = factory_address()?;
+    let poap_factory = PoapFactory::new(factory_address, client.provider());
+    // The signer owns `Poap`, so `owner` is also the creator.
+    let owner = client.owner().await?;
+    let organization_id = U256::from(std::process::id());
+
+    let implementation = poap_factory.getImplementation().call().await?;
+    let salt = factory::collection_salt(
+        owner,
+        organization_id,
+        "Factory Test",
+        "FT",
+        "ipfs://",
+        owner,
+    );
+    let predicted = factory::predict_collection_address(factory_address, implementation, salt);
+    let on_chain = poap_factory
+        .predictCollectionAddress(
+            owner,
+            organization_id,
+            "Factory Test".to_owned(),
+            "FT".to_owned(),
+            "ipfs://".to_owned(),
+            owner,
+        )
+        .call()
+        .await?;
+    assert_eq!(predicted, on_chain);
+
+    poap_factory
+        .createCollectionDeterministic(
+            organization_id,
+            "Factory Test".to_owned(),
+            "FT".to_owned(),
+            "ipfs://".to_owned(),
+            owner,
+        )
+        .send()
+        .await?
+        .get_receipt()
+        .await?;
+
+    let collection = PoapClient::new(predicted, client.provider());
+    assert_eq!(collection.name().await?, "Factory Test");
+    assert_eq!(collection.owner().await?, owner);
+    assert!(poap_factory.isCollection(predicted).call().await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn factory_records_and_pages_collections() -> Result<()> {
+    let client = client()?;
+    let poap_factory = PoapFactory::new(factory_address()?, client.provider());
+    let creator = client.owner().await?;
+    let owner = Address::repeat_byte(0xb0);
+    let first_creator_index = poap_factory
+        .getCreatorCollectionCount(creator)
+        .call()
+        .await?;
+
+    let mut created = Vec::new();
+    for symbol in ["PT1", "PT2"] {
+        let receipt = poap_factory
+            .createCollection(
+                "Page Test".to_owned(),
+                symbol.to_owned(),
+                "ipfs://".to_owned(),
+                owner,
+            )
+            .send()
+            .await?
+            .get_receipt()
+            .await?;
+        assert!(receipt.status());
+        let event = receipt
+            .inner
+            .logs()
+            .iter()
+            .find_map(|log| PoapFactory::CollectionCreated::decode_log_data(log.data()).ok())
+            .expect("createCollection should emit CollectionCreated");
+        assert_eq!(event.creator, creator);
+        assert_eq!(event.owner, owner);
+        assert_eq!(event.name, "Page Test");
+        assert_eq!(event.symbol, symbol);
+        created.push(event.collection);
+    }
+    let (first, second) = (created[0], created[1]);
+
+    for (collection, symbol) in [(first, "PT1"), (second, "PT2")] {
+        assert_eq!(
+            poap_factory.getCollectionCreator(collection).call().await?,
+            creator
+        );
+        assert!(poap_factory.isCollection(collection).call().await?);
+        let collection = PoapClient::new(collection, client.provider());
+        assert_eq!(collection.symbol().await?, symbol);
+        assert_eq!(collection.owner().await?, owner);
+    }
+    assert!(poap_factory
+        .getCollectionCreator(owner)
+        .call()
+        .await?
+        .is_zero());
+
+    // Other tests may create collections concurrently, so look the two up
+    // rather than assuming they are the last entries.
+    let count = poap_factory.getCollectionCount().call().await?;
+    let all = poap_factory
+        .getCollections(U256::ZERO, count)
+        .call()
+        .await?;
+    assert_eq!(U256::from(all.len()), count);
+    let index = all
+        .iter()
+        .position(|collection| *collection == second)
+        .expect("getCollections should list the collection");
+    assert!(all[..index].contains(&first));
+    assert_eq!(
+        poap_factory
+            .getCollections(U256::from(index), U256::from(1))
+            .call()
+            .await?,
+        vec![second]
+    );
+    assert!(poap_factory
+        .getCollections(count, U256::from(10))
+        .call()
+        .await?
+        .is_empty());
+
+    let created_by_creator = poap_factory
+        .getCreatorCollections(creator, first_creator_index, U256::MAX)
+        .call()
+        .await?;
+    let index = created_by_creator
+        .iter()
+        .position(|collection| *collection == first)
+        .expect("getCreatorCollections should list the collection");
+    assert!(created_by_creator[index + 1..].contains(&second));
+    Ok(())
+}
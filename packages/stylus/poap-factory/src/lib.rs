@@ -0,0 +1,268 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::{
+    alloy_primitives::{hex, keccak256, Address, B256, U256},
+    call::Call,
+    deploy::RawDeploy,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageVec},
+    stylus_core::log,
+};
+
+#[path = "../../erc721-example/src/pagination.rs"]
+mod pagination;
+
+use pagination::page_bounds;
+
+/// EIP-1167 creation code up to the implementation address.
+const CLONE_PREFIX: [u8; 20] = hex!("3d602d80600a3d3981f3363d3d373d3d3d363d73");
+/// EIP-1167 creation code after the implementation address.
+const CLONE_SUFFIX: [u8; 15] = hex!("5af43d82803e903d91602b57fd5bf3");
+
+/// Returns the creation code of an EIP-1167 minimal proxy that delegates
+/// every call to `implementation`.
+pub fn clone_code(implementation: Address) -> Vec<u8> {
+    [
+        CLONE_PREFIX.as_slice(),
+        implementation.as_slice(),
+        CLONE_SUFFIX.as_slice(),
+    ]
+    .concat()
+}
+
+/// Returns the CREATE2 salt of a collection. It binds the creator and the
+/// organization id to the initialize arguments, so a deterministic address
+/// can only ever hold the collection it was predicted for, created by the
+/// account it was predicted for. Nobody else can front-run the deployment and
+/// be recorded as the creator. Mirrored off-chain by `poap_client::factory`.
+pub fn collection_salt(
+    creator: Address,
+    organization_id: U256,
+    name: &str,
+    symbol: &str,
+    base_uri: &str,
+    owner: Address,
+) -> B256 {
+    keccak256((creator, organization_id, name, symbol, base_uri, owner).abi_encode_params())
+}
+
+/// Returns the address at which `factory` deploys a clone of `implementation`
+/// with `salt`.
+pub fn predict_clone_address(factory: Address, implementation: Address, salt: B256) -> Address {
+    factory.create2(salt, keccak256(clone_code(implementation)))
+}
+
+sol! {
+    error InvalidImplementation(address implementation);
+    error InvalidCollectionOwner(address owner);
+    error CollectionDeploymentFailed();
+    error CollectionInitializationFailed(address collection);
+
+    event CollectionCreated(
+        address indexed creator,
+        address indexed collection,
+        address indexed owner,
+        string name,
+        string symbol
+    );
+}
+
+sol_interface! {
+    interface IPoap {
+        function initialize(string name, string symbol, string base_uri, address owner) external;
+    }
+}
+
+#[derive(SolidityError)]
+pub enum FactoryError {
+    InvalidImplementation(InvalidImplementation),
+    InvalidCollectionOwner(InvalidCollectionOwner),
+    CollectionDeploymentFailed(CollectionDeploymentFailed),
+    CollectionInitializationFailed(CollectionInitializationFailed),
+}
+
+/// Deploys `Poap` collections for partner organizations.
+///
+/// Every collection is an EIP-1167 clone of one `Poap` implementation,
+/// initialized with its own name, symbol, base URI and owner.
+///
+/// Collections cannot be upgraded. A clone hard-codes the implementation
+/// address and leaves its ERC-1967 slot empty, so `upgradeToAndCall` on a
+/// clone reverts, and a new implementation only reaches collections created
+/// by a new factory. Deploy a collection behind the ERC-1967 proxy in
+/// `scripts/deploy_proxy.ts` instead if it must stay upgradeable.
+#[storage]
+#[entrypoint]
+pub struct PoapFactory {
+    implementation: StorageAddress,
+    collections: StorageVec<StorageAddress>,
+    creator_collections: StorageMap<Address, StorageVec<StorageAddress>>,
+    collection_creator: StorageMap<Address, StorageAddress>,
+}
+
+impl PoapFactory {
+    /// Deploys and initializes a clone, with CREATE2 if `salt` is given.
+    fn deploy_collection(
+        &mut self,
+        salt: Option<B256>,
+        name: String,
+        symbol: String,
+        base_uri: String,
+        owner: Address,
+    ) -> Result<Address, FactoryError> {
+        if owner.is_zero() {
+            return Err(FactoryError::InvalidCollectionOwner(
+                InvalidCollectionOwner { owner },
+            ));
+        }
+
+        let code = clone_code(self.implementation.get());
+        let mut deployer = RawDeploy::new();
+        if let Some(salt) = salt {
+            deployer = deployer.salt(salt);
+        }
+        let collection = unsafe { deployer.deploy(self.vm(), &code, U256::ZERO) }
+            .map_err(|_| FactoryError::CollectionDeploymentFailed(CollectionDeploymentFailed {}))?;
+
+        let context = Call::new_mutating(self);
+        IPoap::new(collection)
+            .initialize(
+                self.vm(),
+                context,
+                name.clone(),
+                symbol.clone(),
+                base_uri,
+                owner,
+            )
+            .map_err(|_| {
+                FactoryError::CollectionInitializationFailed(CollectionInitializationFailed {
+                    collection,
+                })
+            })?;
+
+        let creator = self.vm().msg_sender();
+        self.collections.push(collection);
+        self.creator_collections.setter(creator).push(collection);
+        self.collection_creator.setter(collection).set(creator);
+
+        log(
+            self.vm(),
+            CollectionCreated {
+                creator,
+                collection,
+                owner,
+                name,
+                symbol,
+            },
+        );
+
+        Ok(collection)
+    }
+}
+
+#[public]
+impl PoapFactory {
+    #[constructor]
+    fn constructor(&mut self, implementation: Address) -> Result<(), FactoryError> {
+        if implementation.is_zero() {
+            return Err(FactoryError::InvalidImplementation(InvalidImplementation {
+                implementation,
+            }));
+        }
+        self.implementation.set(implementation);
+        Ok(())
+    }
+
+    /// Clones the `Poap` implementation, initializes the clone and records
+    /// the sender as its creator. Returns the collection address.
+    fn create_collection(
+        &mut self,
+        name: String,
+        symbol: String,
+        base_uri: String,
+        owner: Address,
+    ) -> Result<Address, FactoryError> {
+        self.deploy_collection(None, name, symbol, base_uri, owner)
+    }
+
+    /// Like [`Self::create_collection`], but deploys with CREATE2 at the
+    /// address returned by [`Self::predict_collection_address`] for the
+    /// sender. The same factory and implementation addresses on another chain
+    /// yield the same collection address.
+    fn create_collection_deterministic(
+        &mut self,
+        organization_id: U256,
+        name: String,
+        symbol: String,
+        base_uri: String,
+        owner: Address,
+    ) -> Result<Address, FactoryError> {
+        let creator = self.vm().msg_sender();
+        let salt = collection_salt(creator, organization_id, &name, &symbol, &base_uri, owner);
+        self.deploy_collection(Some(salt), name, symbol, base_uri, owner)
+    }
+
+    // ============ VIEWS ============
+
+    /// Returns the address [`Self::create_collection_deterministic`] deploys
+    /// to when `creator` calls it with these arguments.
+    fn predict_collection_address(
+        &self,
+        creator: Address,
+        organization_id: U256,
+        name: String,
+        symbol: String,
+        base_uri: String,
+        owner: Address,
+    ) -> Address {
+        let salt = collection_salt(creator, organization_id, &name, &symbol, &base_uri, owner);
+        predict_clone_address(
+            self.vm().contract_address(),
+            self.implementation.get(),
+            salt,
+        )
+    }
+
+    /// Returns the `Poap` implementation every collection delegates to.
+    fn get_implementation(&self) -> Address {
+        self.implementation.get()
+    }
+
+    fn get_collection_count(&self) -> U256 {
+        U256::from(self.collections.len())
+    }
+
+    fn get_collections(&self, offset: U256, limit: U256) -> Vec<Address> {
+        page_bounds(self.collections.len(), offset, limit)
+            .filter_map(|i| self.collections.get(i))
+            .collect()
+    }
+
+    fn get_creator_collection_count(&self, creator: Address) -> U256 {
+        U256::from(self.creator_collections.get(creator).len())
+    }
+
+    fn get_creator_collections(&self, creator: Address, offset: U256, limit: U256) -> Vec<Address> {
+        let collections = self.creator_collections.get(creator);
+        page_bounds(collections.len(), offset, limit)
+            .filter_map(|i| collections.get(i))
+            .collect()
+    }
+
+    /// Returns who created `collection`, or the zero address if this factory
+    /// did not deploy it.
+    fn get_collection_creator(&self, collection: Address) -> Address {
+        self.collection_creator.get(collection)
+    }
+
+    fn is_collection(&self, collection: Address) -> bool {
+        !self.collection_creator.get(collection).is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests;
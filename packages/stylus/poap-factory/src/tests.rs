@@ -0,0 +1,155 @@
+use alloc::vec;
+
+use motsu::prelude::*;
+use stylus_sdk::alloy_primitives::{address, uint};
+
+use super::*;
+
+const IMPLEMENTATION: Address = address!("0x00000000000000000000000000000000000000aa");
+
+#[test]
+fn clone_code_embeds_implementation() {
+    let code = clone_code(IMPLEMENTATION);
+
+    assert_eq!(code.len(), 55);
+    assert_eq!(&code[..20], CLONE_PREFIX.as_slice());
+    assert_eq!(&code[20..40], IMPLEMENTATION.as_slice());
+    assert_eq!(&code[40..], CLONE_SUFFIX.as_slice());
+}
+
+#[motsu::test]
+fn constructor_stores_implementation(contract: Contract<PoapFactory>, alice: Address) {
+    contract
+        .sender(alice)
+        .constructor(IMPLEMENTATION)
+        .motsu_expect("constructor should accept the implementation");
+
+    assert_eq!(contract.sender(alice).get_implementation(), IMPLEMENTATION);
+    assert_eq!(contract.sender(alice).get_collection_count(), U256::ZERO);
+    assert_eq!(
+        contract
+            .sender(alice)
+            .get_collections(U256::ZERO, uint!(10_U256)),
+        vec![]
+    );
+    assert_eq!(
+        contract.sender(alice).get_creator_collection_count(alice),
+        U256::ZERO
+    );
+    assert!(!contract.sender(alice).is_collection(IMPLEMENTATION));
+}
+
+#[motsu::test]
+fn constructor_rejects_zero_implementation(contract: Contract<PoapFactory>, alice: Address) {
+    let err = contract
+        .sender(alice)
+        .constructor(Address::ZERO)
+        .motsu_unwrap_err();
+
+    assert!(matches!(err, FactoryError::InvalidImplementation(_)));
+}
+
+#[motsu::test]
+fn create_collection_rejects_zero_owner(contract: Contract<PoapFactory>, alice: Address) {
+    contract
+        .sender(alice)
+        .constructor(IMPLEMENTATION)
+        .motsu_unwrap();
+
+    let err = contract
+        .sender(alice)
+        .create_collection(
+            String::from("ETHGlobal"),
+            String::from("ETHG"),
+            String::from("ipfs://"),
+            Address::ZERO,
+        )
+        .motsu_unwrap_err();
+
+    assert!(matches!(
+        err,
+        FactoryError::InvalidCollectionOwner(InvalidCollectionOwner { owner }) if owner.is_zero()
+    ));
+    assert_eq!(contract.sender(alice).get_collection_count(), U256::ZERO);
+}
+
+#[test]
+fn collection_salt_binds_every_argument() {
+    let creator = address!("0x00000000000000000000000000000000000000c0");
+    let owner = address!("0x00000000000000000000000000000000000000b0");
+    let salt = collection_salt(
+        creator,
+        uint!(1_U256),
+        "ETHGlobal",
+        "ETHG",
+        "ipfs://",
+        owner,
+    );
+
+    for other in [
+        collection_salt(owner, uint!(1_U256), "ETHGlobal", "ETHG", "ipfs://", owner),
+        collection_salt(
+            creator,
+            uint!(2_U256),
+            "ETHGlobal",
+            "ETHG",
+            "ipfs://",
+            owner,
+        ),
+        collection_salt(
+            creator,
+            uint!(1_U256),
+            "ETHGlobal2",
+            "ETHG",
+            "ipfs://",
+            owner,
+        ),
+        collection_salt(
+            creator,
+            uint!(1_U256),
+            "ETHGlobal",
+            "ETHG2",
+            "ipfs://",
+            owner,
+        ),
+        collection_salt(creator, uint!(1_U256), "ETHGlobal", "ETHG", "ar://", owner),
+        collection_salt(
+            creator,
+            uint!(1_U256),
+            "ETHGlobal",
+            "ETHG",
+            "ipfs://",
+            IMPLEMENTATION,
+        ),
+    ] {
+        assert_ne!(salt, other);
+    }
+}
+
+#[motsu::test]
+fn predicts_create2_clone_address(contract: Contract<PoapFactory>, alice: Address, bob: Address) {
+    contract
+        .sender(alice)
+        .constructor(IMPLEMENTATION)
+        .motsu_unwrap();
+
+    let predict = |creator| {
+        contract.sender(alice).predict_collection_address(
+            creator,
+            uint!(7_U256),
+            String::from("ETHGlobal"),
+            String::from("ETHG"),
+            String::from("ipfs://"),
+            alice,
+        )
+    };
+    let predicted = predict(bob);
+
+    let salt = collection_salt(bob, uint!(7_U256), "ETHGlobal", "ETHG", "ipfs://", alice);
+    let mut preimage = vec![0xff];
+    preimage.extend_from_slice(contract.address().as_slice());
+    preimage.extend_from_slice(salt.as_slice());
+    preimage.extend_from_slice(keccak256(clone_code(IMPLEMENTATION)).as_slice());
+    assert_eq!(predicted, Address::from_slice(&keccak256(preimage)[12..]));
+    assert_ne!(predict(alice), predicted);
+}
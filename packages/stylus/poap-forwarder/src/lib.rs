@@ -0,0 +1,111 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+extern crate alloc;
+
+pub mod signature;
+
+use alloc::vec::Vec;
+
+use alloy_sol_types::{sol, SolValue};
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{keccak256, Address, B256, U256},
+    call::Call,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+    stylus_core::log,
+};
+
+sol! {
+    error InvalidSigner(address from);
+    error InvalidNonce(address from, uint256 nonce);
+
+    event Forwarded(address indexed from, address indexed to, uint256 nonce);
+}
+
+sol_interface! {
+    interface IPoapForwardable {
+        function executeForwarded(bytes calldata data) external returns (bytes memory);
+    }
+}
+
+#[derive(SolidityError)]
+pub enum ForwarderError {
+    InvalidSigner(InvalidSigner),
+    InvalidNonce(InvalidNonce),
+}
+
+/// Minimal ERC-2771 forwarder for local testing of gasless `Poap` calls.
+///
+/// A relayer submits a call signed by `from` with `personal_sign` over
+/// [`PoapForwarder::get_request_hash`]. Contract wallets such as Safe
+/// multisigs approve the same hash through ERC-1271 `isValidSignature`. The
+/// forwarder checks the signature and nonce, appends `from` to the calldata
+/// and hands it to the target's `executeForwarded`. Reverts of the relayed
+/// call are bubbled up unchanged.
+#[storage]
+#[entrypoint]
+pub struct PoapForwarder {
+    nonces: StorageMap<Address, StorageU256>,
+}
+
+#[public]
+impl PoapForwarder {
+    /// Returns the nonce the next request of `from` must carry.
+    fn get_nonce(&self, from: Address) -> U256 {
+        self.nonces.get(from)
+    }
+
+    /// Returns the hash `from` signs, binding the request to this chain and
+    /// forwarder.
+    fn get_request_hash(&self, from: Address, to: Address, nonce: U256, data: Bytes) -> B256 {
+        let chain_id = U256::from(self.vm().chain_id());
+        let forwarder = self.vm().contract_address();
+        keccak256(
+            (
+                chain_id,
+                forwarder,
+                from,
+                to,
+                nonce,
+                keccak256(data.as_slice()),
+            )
+                .abi_encode_params(),
+        )
+    }
+
+    /// Relays `data` to `to` on behalf of `from` and returns the result.
+    fn execute(
+        &mut self,
+        from: Address,
+        to: Address,
+        nonce: U256,
+        data: Bytes,
+        signature: Bytes,
+    ) -> Result<Bytes, Vec<u8>> {
+        let expected = self.nonces.get(from);
+        if nonce != expected {
+            return Err(ForwarderError::InvalidNonce(InvalidNonce { from, nonce }).into());
+        }
+
+        let mut calldata = data.to_vec();
+        calldata.extend_from_slice(from.as_slice());
+
+        let hash = signature::eth_signed_message_hash(self.get_request_hash(from, to, nonce, data));
+        if !signature::is_valid_signature_now(self.vm(), from, hash, &signature) {
+            return Err(ForwarderError::InvalidSigner(InvalidSigner { from }).into());
+        }
+        self.nonces.setter(from).set(expected + U256::from(1));
+
+        let context = Call::new_mutating(self);
+        let output = IPoapForwardable::new(to)
+            .execute_forwarded(self.vm(), context, calldata.into())
+            .map_err(Vec::<u8>::from)?;
+
+        log(self.vm(), Forwarded { from, to, nonce });
+
+        Ok(output.to_vec().into())
+    }
+}
+
+#[cfg(test)]
+mod tests;
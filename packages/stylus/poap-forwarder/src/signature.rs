@@ -0,0 +1,73 @@
+//! Signature checks for EOAs, through the `ecrecover` precompile, and for
+//! smart-contract wallets, through ERC-1271.
+use alloc::vec::Vec;
+
+use stylus_sdk::{
+    alloy_primitives::{address, aliases::B32, b256, fixed_bytes, keccak256, Address, B256},
+    call::Call,
+    host::VM,
+    prelude::*,
+};
+
+sol_interface! {
+    interface IErc1271 {
+        function isValidSignature(bytes32 hash, bytes signature) external view returns (bytes4);
+    }
+}
+
+/// Value `isValidSignature` returns for a valid signature.
+const ERC1271_MAGIC_VALUE: B32 = fixed_bytes!("1626ba7e");
+
+const ECRECOVER: Address = address!("0x0000000000000000000000000000000000000001");
+
+/// Half the secp256k1 order. Signatures with a larger `s` are malleable
+/// copies of another valid signature and are rejected.
+const MAX_S: B256 = b256!("0x7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+
+/// Returns the EIP-191 hash `personal_sign` produces for a 32-byte message.
+pub fn eth_signed_message_hash(hash: B256) -> B256 {
+    keccak256(
+        [
+            b"\x19Ethereum Signed Message:\n32".as_slice(),
+            hash.as_slice(),
+        ]
+        .concat(),
+    )
+}
+
+/// Recovers the signer of `hash` from a 65-byte `r || s || v` signature.
+///
+/// Returns `None` for malformed or malleable signatures and when the
+/// precompile recovers no address.
+pub fn recover(vm: &VM, hash: B256, signature: &[u8]) -> Option<Address> {
+    let [r @ .., v] = <[u8; 65]>::try_from(signature).ok()?;
+    let (r, s) = r.split_at(32);
+    if B256::from_slice(s) > MAX_S || !matches!(v, 27 | 28) {
+        return None;
+    }
+
+    let mut input = Vec::with_capacity(128);
+    input.extend_from_slice(hash.as_slice());
+    input.extend_from_slice(&[0; 31]);
+    input.push(v);
+    input.extend_from_slice(r);
+    input.extend_from_slice(s);
+
+    let output = vm.static_call(&Call::new(), ECRECOVER, &input).ok()?;
+    let signer = Address::from_slice(output.get(12..32)?);
+    (!signer.is_zero()).then_some(signer)
+}
+
+/// Returns whether `signature` is `signer`'s signature of `hash`.
+///
+/// An ECDSA signature recovering to `signer` is accepted first. Otherwise
+/// `signer` is asked through ERC-1271's `isValidSignature`, so contract
+/// wallets such as Safe multisigs can sign; an EOA never passes that check.
+pub fn is_valid_signature_now(vm: &VM, signer: Address, hash: B256, signature: &[u8]) -> bool {
+    if recover(vm, hash, signature) == Some(signer) {
+        return true;
+    }
+    IErc1271::new(signer)
+        .is_valid_signature(vm, Call::new(), hash, signature.to_vec().into())
+        .is_ok_and(|magic| magic == ERC1271_MAGIC_VALUE)
+}
@@ -0,0 +1,210 @@
+use alloc::vec;
+
+use motsu::prelude::*;
+use stylus_sdk::{
+    alloy_primitives::{address, aliases::B32, fixed_bytes, uint},
+    storage::{StorageB256, StorageBytes},
+};
+
+use super::*;
+
+const POAP: Address = address!("0x00000000000000000000000000000000000000aa");
+
+#[storage]
+struct Erc1271WalletMock {
+    approved_hash: StorageB256,
+}
+
+unsafe impl TopLevelStorage for Erc1271WalletMock {}
+
+#[public]
+impl Erc1271WalletMock {
+    fn approve(&mut self, hash: B256) {
+        self.approved_hash.set(hash);
+    }
+
+    #[selector(name = "isValidSignature")]
+    fn is_valid_signature(&self, hash: B256, _signature: Bytes) -> B32 {
+        if hash == self.approved_hash.get() {
+            fixed_bytes!("1626ba7e")
+        } else {
+            B32::ZERO
+        }
+    }
+}
+
+#[storage]
+struct ForwardableMock {
+    received: StorageBytes,
+}
+
+unsafe impl TopLevelStorage for ForwardableMock {}
+
+#[public]
+impl ForwardableMock {
+    #[selector(name = "executeForwarded")]
+    fn execute_forwarded(&mut self, data: Bytes) -> Bytes {
+        self.received.set_bytes(data.as_slice());
+        data
+    }
+
+    fn received(&self) -> Bytes {
+        self.received.get_bytes().into()
+    }
+}
+
+fn request_hash(
+    contract: &Contract<PoapForwarder>,
+    from: Address,
+    to: Address,
+    data: &Bytes,
+) -> B256 {
+    signature::eth_signed_message_hash(contract.sender(from).get_request_hash(
+        from,
+        to,
+        U256::ZERO,
+        data.clone(),
+    ))
+}
+
+#[motsu::test]
+fn nonces_start_at_zero(contract: Contract<PoapForwarder>, alice: Address) {
+    assert_eq!(contract.sender(alice).get_nonce(alice), U256::ZERO);
+}
+
+#[motsu::test]
+fn request_hash_binds_every_field(contract: Contract<PoapForwarder>, alice: Address, bob: Address) {
+    let data = || Bytes::from(vec![1, 2, 3]);
+    let hash = contract
+        .sender(alice)
+        .get_request_hash(alice, POAP, U256::ZERO, data());
+
+    for other in [
+        contract
+            .sender(alice)
+            .get_request_hash(bob, POAP, U256::ZERO, data()),
+        contract
+            .sender(alice)
+            .get_request_hash(alice, bob, U256::ZERO, data()),
+        contract
+            .sender(alice)
+            .get_request_hash(alice, POAP, uint!(1_U256), data()),
+        contract
+            .sender(alice)
+            .get_request_hash(alice, POAP, U256::ZERO, Bytes::from(vec![1, 2])),
+    ] {
+        assert_ne!(hash, other);
+    }
+}
+
+#[motsu::test]
+fn execute_rejects_stale_nonce(contract: Contract<PoapForwarder>, alice: Address) {
+    let err = contract
+        .sender(alice)
+        .execute(
+            alice,
+            POAP,
+            uint!(1_U256),
+            Bytes::from(vec![]),
+            Bytes::from(vec![]),
+        )
+        .motsu_unwrap_err();
+
+    let expected: Vec<u8> = ForwarderError::InvalidNonce(InvalidNonce {
+        from: alice,
+        nonce: uint!(1_U256),
+    })
+    .into();
+    assert_eq!(err, expected);
+}
+
+#[motsu::test]
+fn execute_rejects_malformed_signature(contract: Contract<PoapForwarder>, alice: Address) {
+    let err = contract
+        .sender(alice)
+        .execute(
+            alice,
+            POAP,
+            U256::ZERO,
+            Bytes::from(vec![]),
+            Bytes::from(vec![0; 64]),
+        )
+        .motsu_unwrap_err();
+
+    let expected: Vec<u8> = ForwarderError::InvalidSigner(InvalidSigner { from: alice }).into();
+    assert_eq!(err, expected);
+    assert_eq!(contract.sender(alice).get_nonce(alice), U256::ZERO);
+}
+
+#[test]
+fn signed_message_hash_matches_eip191() {
+    let hash = B256::repeat_byte(0x11);
+    let mut message = b"\x19Ethereum Signed Message:\n32".to_vec();
+    message.extend_from_slice(hash.as_slice());
+
+    assert_eq!(signature::eth_signed_message_hash(hash), keccak256(message));
+}
+
+#[motsu::test]
+fn execute_accepts_erc1271_approval(
+    contract: Contract<PoapForwarder>,
+    wallet: Contract<Erc1271WalletMock>,
+    target: Contract<ForwardableMock>,
+    alice: Address,
+) {
+    let data = Bytes::from(vec![1, 2, 3]);
+    let hash = request_hash(&contract, wallet.address(), target.address(), &data);
+    wallet.sender(alice).approve(hash);
+
+    contract
+        .sender(alice)
+        .execute(
+            wallet.address(),
+            target.address(),
+            U256::ZERO,
+            data.clone(),
+            Bytes::from(vec![0xaa; 96]),
+        )
+        .motsu_expect("wallet approval should authorize the request");
+
+    let mut expected = data.to_vec();
+    expected.extend_from_slice(wallet.address().as_slice());
+    assert_eq!(target.sender(alice).received().to_vec(), expected);
+    assert_eq!(
+        contract.sender(alice).get_nonce(wallet.address()),
+        uint!(1_U256)
+    );
+    assert!(contract.emitted(&Forwarded {
+        from: wallet.address(),
+        to: target.address(),
+        nonce: U256::ZERO,
+    }));
+}
+
+#[motsu::test]
+fn execute_rejects_unapproved_erc1271_request(
+    contract: Contract<PoapForwarder>,
+    wallet: Contract<Erc1271WalletMock>,
+    target: Contract<ForwardableMock>,
+    alice: Address,
+) {
+    wallet.sender(alice).approve(B256::repeat_byte(1));
+
+    let err = contract
+        .sender(alice)
+        .execute(
+            wallet.address(),
+            target.address(),
+            U256::ZERO,
+            Bytes::from(vec![1, 2, 3]),
+            Bytes::from(vec![0xaa; 96]),
+        )
+        .motsu_unwrap_err();
+
+    let expected: Vec<u8> = ForwarderError::InvalidSigner(InvalidSigner {
+        from: wallet.address(),
+    })
+    .into();
+    assert_eq!(err, expected);
+    assert_eq!(target.sender(alice).received().to_vec(), Vec::<u8>::new());
+}
@@ -0,0 +1,19 @@
+use alloy::transports::{RpcError, TransportErrorKind};
+
+/// Errors returned by the [`crate::Indexer`] and [`crate::Store`].
+#[derive(Debug, thiserror::Error)]
+pub enum IndexerError {
+    /// A database query failed.
+    #[error(transparent)]
+    Database(#[from] rusqlite::Error),
+    /// An RPC request failed.
+    #[error(transparent)]
+    Rpc(#[from] RpcError<TransportErrorKind>),
+    /// The node does not know a block it reported as part of the chain.
+    #[error("block {0} not found")]
+    MissingBlock(u64),
+    /// A log was returned without its block number, hash or index, which
+    /// only happens for pending logs.
+    #[error("log is missing its block position")]
+    PendingLog,
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+
+use alloy::{
+    eips::BlockNumberOrTag,
+    primitives::{Address, B256},
+    providers::Provider,
+    rpc::types::Filter,
+};
+
+use crate::{IndexerError, Store};
+
+/// Default number of blocks requested per `eth_getLogs` call.
+pub const DEFAULT_BATCH_SIZE: u64 = 1_000;
+
+/// Number of indexed blocks compared against the chain to find the fork
+/// point of a reorg. Deeper reorgs are not detected.
+pub const REORG_DEPTH: usize = 64;
+
+/// Outcome of a single [`Indexer::sync_once`] step.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// First orphaned block, if a reorg was rolled back.
+    pub rolled_back_from: Option<u64>,
+    /// Inclusive range of blocks indexed, if any.
+    pub indexed: Option<(u64, u64)>,
+    /// Inclusive range of blocks fetched but not indexed, because the chain
+    /// changed while their logs were fetched. The next step retries it.
+    pub discarded: Option<(u64, u64)>,
+    /// Number of logs fetched for the range.
+    pub logs: usize,
+}
+
+/// Follows a `Poap` contract's logs into a [`Store`].
+pub struct Indexer<P> {
+    provider: P,
+    contract: Address,
+    store: Store,
+    start_block: u64,
+    batch_size: u64,
+}
+
+impl<P: Provider> Indexer<P> {
+    /// Creates an indexer for `contract` that starts at `start_block` when
+    /// `store` is empty, and resumes after its latest block otherwise.
+    pub fn new(provider: P, contract: Address, store: Store, start_block: u64) -> Self {
+        Self {
+            provider,
+            contract,
+            store,
+            start_block,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Sets the number of blocks requested per `eth_getLogs` call.
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    /// Rolls back a reorg if one happened, then indexes the next batch of
+    /// blocks up to the chain head. The batch is only committed if all its
+    /// logs belong to the canonical chain.
+    pub async fn sync_once(&mut self) -> Result<SyncReport, IndexerError> {
+        let mut report = SyncReport {
+            rolled_back_from: self.reconcile().await?,
+            ..SyncReport::default()
+        };
+
+        let head = self.provider.get_block_number().await?;
+        let from = self
+            .store
+            .latest_block()?
+            .map_or(self.start_block, |latest| latest + 1);
+        if from > head {
+            return Ok(report);
+        }
+        let to = head.min(from + self.batch_size - 1);
+
+        let filter = Filter::new()
+            .address(self.contract)
+            .from_block(from)
+            .to_block(to);
+        let checkpoint = self.block_hash(to).await?;
+        let logs = self.provider.get_logs(&filter).await?;
+
+        let mut canonical = BTreeMap::from([(to, checkpoint)]);
+        for log in &logs {
+            let (Some(number), Some(hash)) = (log.block_number, log.block_hash) else {
+                return Err(IndexerError::PendingLog);
+            };
+            let canonical_hash = match canonical.get(&number) {
+                Some(&canonical_hash) => canonical_hash,
+                None => {
+                    let canonical_hash = self.block_hash(number).await?;
+                    canonical.insert(number, canonical_hash);
+                    canonical_hash
+                }
+            };
+            if hash != canonical_hash {
+                report.discarded = Some((from, to));
+                return Ok(report);
+            }
+        }
+        self.store.apply(&logs, (to, checkpoint))?;
+
+        report.indexed = Some((from, to));
+        report.logs = logs.len();
+        Ok(report)
+    }
+
+    /// Indexes up to the current chain head.
+    pub async fn sync_to_head(&mut self) -> Result<(), IndexerError> {
+        loop {
+            let report = self.sync_once().await?;
+            if report.indexed.is_none() && report.discarded.is_none() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Compares the most recent indexed blocks with the chain and rolls back
+    /// those that are no longer canonical. Returns the first rolled back
+    /// block.
+    async fn reconcile(&mut self) -> Result<Option<u64>, IndexerError> {
+        let mut fork = None;
+        for (number, hash) in self.store.recent_blocks(REORG_DEPTH)? {
+            let canonical = self
+                .provider
+                .get_block_by_number(BlockNumberOrTag::Number(number))
+                .await?
+                .map(|block| block.header.hash);
+            if canonical == Some(hash) {
+                break;
+            }
+            fork = Some(number);
+        }
+        if let Some(number) = fork {
+            self.store.rollback(number)?;
+        }
+        Ok(fork)
+    }
+
+    async fn block_hash(&self, number: u64) -> Result<B256, IndexerError> {
+        self.provider
+            .get_block_by_number(BlockNumberOrTag::Number(number))
+            .await?
+            .map(|block| block.header.hash)
+            .ok_or(IndexerError::MissingBlock(number))
+    }
+}
@@ -0,0 +1,16 @@
+//! Off-chain index of `Poap` state.
+//!
+//! [`Indexer`] follows the contract's `Transfer` and lifecycle logs
+//! (`EventCreated`, `EventMinterAdded`, `BadgeMinted`) and materializes them
+//! into a SQLite [`Store`], which answers queries about events, minters,
+//! tokens and holders.
+//!
+//! Every row is tagged with the block that produced it, so a reorg is undone
+//! by deleting the rows of orphaned blocks and indexing the new ones.
+mod error;
+mod indexer;
+mod store;
+
+pub use error::IndexerError;
+pub use indexer::{Indexer, SyncReport, DEFAULT_BATCH_SIZE, REORG_DEPTH};
+pub use store::{Badge, Event, Store};
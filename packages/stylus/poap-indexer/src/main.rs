@@ -0,0 +1,128 @@
+//! `poap-indexer`: follows a `Poap` contract into SQLite and queries the
+//! resulting index.
+use std::{path::PathBuf, time::Duration};
+
+use alloy::{
+    primitives::{Address, U256},
+    providers::ProviderBuilder,
+};
+use clap::{Parser, Subcommand};
+use eyre::{eyre, Result, WrapErr};
+use poap_indexer::{Indexer, Store, DEFAULT_BATCH_SIZE};
+
+const DEVNODE_RPC_URL: &str = "http://127.0.0.1:8547";
+
+#[derive(Parser)]
+#[command(name = "poap-indexer", about = "Index Poap logs into SQLite")]
+struct Cli {
+    /// Path of the SQLite database.
+    #[arg(
+        long,
+        env = "POAP_INDEX_DB",
+        default_value = "poap-index.sqlite",
+        global = true
+    )]
+    db: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Follow the contract's logs, polling for new blocks.
+    Run {
+        /// Address of the deployed Poap contract.
+        #[arg(long, env = "POAP_ADDRESS")]
+        contract: Address,
+        /// JSON-RPC endpoint of the chain.
+        #[arg(long, env = "RPC_URL", default_value = DEVNODE_RPC_URL)]
+        rpc_url: String,
+        /// Block to start from when the database is empty, usually the
+        /// deployment block.
+        #[arg(long, default_value_t = 0)]
+        from_block: u64,
+        /// Number of blocks requested per `eth_getLogs` call.
+        #[arg(long, default_value_t = DEFAULT_BATCH_SIZE)]
+        batch_size: u64,
+        /// Delay between polls once the index is at the chain head.
+        #[arg(long, default_value_t = 1_000)]
+        poll_interval_ms: u64,
+    },
+    /// Print an indexed event, its minters and attendees.
+    Event { event_id: U256 },
+    /// Print the events created for an organizer.
+    Organizer { organizer: Address },
+    /// Print the badges currently held by an account.
+    Holder { account: Address },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let store = Store::open(&cli.db)
+        .wrap_err_with(|| format!("cannot open database {}", cli.db.display()))?;
+
+    match cli.command {
+        Command::Run {
+            contract,
+            rpc_url,
+            from_block,
+            batch_size,
+            poll_interval_ms,
+        } => {
+            let provider = ProviderBuilder::new().connect_http(rpc_url.parse()?);
+            let mut indexer =
+                Indexer::new(provider, contract, store, from_block).with_batch_size(batch_size);
+            loop {
+                let report = indexer.sync_once().await?;
+                if let Some(block) = report.rolled_back_from {
+                    println!("reorg: rolled back from block {block}");
+                }
+                if let Some((from, to)) = report.discarded {
+                    println!("reorg: discarded blocks {from}..={to}, retrying");
+                    continue;
+                }
+                match report.indexed {
+                    Some((from, to)) => {
+                        println!("indexed blocks {from}..={to} ({} logs)", report.logs)
+                    }
+                    None => tokio::time::sleep(Duration::from_millis(poll_interval_ms)).await,
+                }
+            }
+        }
+        Command::Event { event_id } => {
+            let event = store
+                .event(event_id)?
+                .ok_or_else(|| eyre!("event {event_id} is not indexed"))?;
+            println!("event      {}", event.id);
+            println!("name       {}", event.name);
+            println!("organizer  {}", event.organizer);
+            println!("block      {}", event.block_number);
+            println!("minters");
+            for minter in store.event_minters(event_id)? {
+                println!("  {minter}");
+            }
+            println!("attendees");
+            for attendee in store.event_attendees(event_id)? {
+                println!("  {attendee}");
+            }
+        }
+        Command::Organizer { organizer } => {
+            for event in store.events_by_organizer(organizer)? {
+                println!("{}  {}", event.id, event.name);
+            }
+        }
+        Command::Holder { account } => {
+            for badge in store.holder_badges(account)? {
+                match (badge.event_id, badge.event_name) {
+                    (Some(event_id), Some(name)) => {
+                        println!("token {} event {event_id} ({name})", badge.token_id)
+                    }
+                    _ => println!("token {}", badge.token_id),
+                }
+            }
+        }
+    }
+    Ok(())
+}
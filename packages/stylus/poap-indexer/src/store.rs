@@ -0,0 +1,545 @@
+use std::{path::Path, str::FromStr};
+
+use alloy::{
+    primitives::{Address, B256, U256},
+    rpc::types::Log,
+};
+use poap_client::{decode_log, PoapEvents};
+use rusqlite::{
+    params,
+    types::{Type, ValueRef},
+    Connection, OptionalExtension, Row, Transaction,
+};
+
+use crate::IndexerError;
+
+/// Log tables are append-only and keyed by the log's position, so rolling
+/// back a block is a plain delete. Current ownership is derived from the
+/// latest transfer of each token.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS blocks (
+    number INTEGER PRIMARY KEY,
+    hash TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS events (
+    block_number INTEGER NOT NULL,
+    log_index INTEGER NOT NULL,
+    event_id TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    organizer TEXT NOT NULL,
+    PRIMARY KEY (block_number, log_index)
+);
+CREATE INDEX IF NOT EXISTS events_by_organizer ON events (organizer);
+CREATE TABLE IF NOT EXISTS event_minters (
+    block_number INTEGER NOT NULL,
+    log_index INTEGER NOT NULL,
+    event_id TEXT NOT NULL,
+    minter TEXT NOT NULL,
+    PRIMARY KEY (block_number, log_index)
+);
+CREATE INDEX IF NOT EXISTS event_minters_by_event ON event_minters (event_id);
+CREATE TABLE IF NOT EXISTS badges (
+    block_number INTEGER NOT NULL,
+    log_index INTEGER NOT NULL,
+    token_id TEXT NOT NULL UNIQUE,
+    event_id TEXT NOT NULL,
+    attendee TEXT NOT NULL,
+    PRIMARY KEY (block_number, log_index)
+);
+CREATE INDEX IF NOT EXISTS badges_by_event ON badges (event_id);
+CREATE TABLE IF NOT EXISTS transfers (
+    block_number INTEGER NOT NULL,
+    log_index INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    from_address TEXT NOT NULL,
+    to_address TEXT NOT NULL,
+    PRIMARY KEY (block_number, log_index)
+);
+CREATE INDEX IF NOT EXISTS transfers_by_token ON transfers (token_id);
+CREATE VIEW IF NOT EXISTS token_owners AS
+    SELECT t.token_id, t.to_address AS owner
+    FROM transfers t
+    WHERE t.to_address != '0x0000000000000000000000000000000000000000'
+      AND NOT EXISTS (
+        SELECT 1 FROM transfers later
+        WHERE later.token_id = t.token_id
+          AND (later.block_number, later.log_index) > (t.block_number, t.log_index)
+      );
+";
+
+/// Tables holding rows derived from logs, cleared on rollback.
+const LOG_TABLES: [&str; 4] = ["events", "event_minters", "badges", "transfers"];
+
+/// An indexed event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub id: U256,
+    pub name: String,
+    pub organizer: Address,
+    pub block_number: u64,
+}
+
+/// A token currently held by an account.
+///
+/// `event_id` is `None` for tokens minted without a `BadgeMinted` log, such
+/// as achievement badges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Badge {
+    pub token_id: U256,
+    pub owner: Address,
+    pub event_id: Option<U256>,
+    pub event_name: Option<String>,
+}
+
+/// SQLite-backed store of indexed `Poap` state.
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    /// Opens or creates the database at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, IndexerError> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Creates a store that only lives in memory.
+    pub fn in_memory() -> Result<Self, IndexerError> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, IndexerError> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Returns the highest indexed block.
+    pub fn latest_block(&self) -> Result<Option<u64>, IndexerError> {
+        Ok(self
+            .conn
+            .query_row("SELECT MAX(number) FROM blocks", [], |row| row.get(0))?)
+    }
+
+    /// Returns up to `limit` indexed blocks, newest first.
+    pub fn recent_blocks(&self, limit: usize) -> Result<Vec<(u64, B256)>, IndexerError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT number, hash FROM blocks ORDER BY number DESC LIMIT ?1")?;
+        let blocks = stmt
+            .query_map([limit as i64], |row| Ok((row.get(0)?, column(row, 1)?)))?
+            .collect::<Result<_, _>>()?;
+        Ok(blocks)
+    }
+
+    /// Indexes `logs` and records `checkpoint` as the last indexed block.
+    ///
+    /// Logs that are not `Poap` events are ignored. All rows are written in a
+    /// single transaction.
+    pub fn apply(&mut self, logs: &[Log], checkpoint: (u64, B256)) -> Result<(), IndexerError> {
+        let tx = self.conn.transaction()?;
+        for log in logs {
+            insert_log(&tx, log)?;
+        }
+        insert_block(&tx, checkpoint.0, checkpoint.1)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Deletes everything indexed from `block_number` onwards.
+    pub fn rollback(&mut self, block_number: u64) -> Result<(), IndexerError> {
+        let tx = self.conn.transaction()?;
+        for table in LOG_TABLES {
+            tx.execute(
+                &format!("DELETE FROM {table} WHERE block_number >= ?1"),
+                [block_number],
+            )?;
+        }
+        tx.execute("DELETE FROM blocks WHERE number >= ?1", [block_number])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    // ============ QUERIES ============
+
+    pub fn event(&self, event_id: U256) -> Result<Option<Event>, IndexerError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT event_id, name, organizer, block_number FROM events WHERE event_id = ?1",
+                [encode_u256(event_id)],
+                event_from_row,
+            )
+            .optional()?)
+    }
+
+    pub fn events_by_organizer(&self, organizer: Address) -> Result<Vec<Event>, IndexerError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT event_id, name, organizer, block_number FROM events
+             WHERE organizer = ?1 ORDER BY event_id",
+        )?;
+        let events = stmt
+            .query_map([encode_address(organizer)], event_from_row)?
+            .collect::<Result<_, _>>()?;
+        Ok(events)
+    }
+
+    /// Returns the organizer and every account added as a minter of
+    /// `event_id`.
+    pub fn event_minters(&self, event_id: U256) -> Result<Vec<Address>, IndexerError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT organizer FROM events WHERE event_id = ?1
+             UNION
+             SELECT minter FROM event_minters WHERE event_id = ?1
+             ORDER BY 1",
+        )?;
+        let minters = stmt
+            .query_map([encode_u256(event_id)], |row| column(row, 0))?
+            .collect::<Result<_, _>>()?;
+        Ok(minters)
+    }
+
+    /// Returns the current holders of `event_id`'s badges, in mint order.
+    pub fn event_attendees(&self, event_id: U256) -> Result<Vec<Address>, IndexerError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT o.owner FROM badges b
+             JOIN token_owners o ON o.token_id = b.token_id
+             WHERE b.event_id = ?1
+             ORDER BY b.block_number, b.log_index",
+        )?;
+        let attendees = stmt
+            .query_map([encode_u256(event_id)], |row| column(row, 0))?
+            .collect::<Result<_, _>>()?;
+        Ok(attendees)
+    }
+
+    /// Returns the tokens currently held by `owner`, by token id.
+    pub fn holder_badges(&self, owner: Address) -> Result<Vec<Badge>, IndexerError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT o.token_id, o.owner, b.event_id, e.name FROM token_owners o
+             LEFT JOIN badges b ON b.token_id = o.token_id
+             LEFT JOIN events e ON e.event_id = b.event_id
+             WHERE o.owner = ?1
+             ORDER BY o.token_id",
+        )?;
+        let badges = stmt
+            .query_map([encode_address(owner)], badge_from_row)?
+            .collect::<Result<_, _>>()?;
+        Ok(badges)
+    }
+
+    /// Returns a token and its current owner, or `None` if it does not
+    /// exist or was burned.
+    pub fn token(&self, token_id: U256) -> Result<Option<Badge>, IndexerError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT o.token_id, o.owner, b.event_id, e.name FROM token_owners o
+                 LEFT JOIN badges b ON b.token_id = o.token_id
+                 LEFT JOIN events e ON e.event_id = b.event_id
+                 WHERE o.token_id = ?1",
+                [encode_u256(token_id)],
+                badge_from_row,
+            )
+            .optional()?)
+    }
+}
+
+fn insert_block(tx: &Transaction, number: u64, hash: B256) -> rusqlite::Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO blocks (number, hash) VALUES (?1, ?2)",
+        params![number, hash.to_string()],
+    )?;
+    Ok(())
+}
+
+fn insert_log(tx: &Transaction, log: &Log) -> Result<(), IndexerError> {
+    let (Some(block_number), Some(block_hash), Some(log_index)) =
+        (log.block_number, log.block_hash, log.log_index)
+    else {
+        return Err(IndexerError::PendingLog);
+    };
+    let Some(event) = decode_log(log) else {
+        return Ok(());
+    };
+
+    match event {
+        PoapEvents::EventCreated(created) => {
+            tx.execute(
+                "INSERT INTO events (block_number, log_index, event_id, name, organizer)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    block_number,
+                    log_index,
+                    encode_u256(created.eventId),
+                    created.name,
+                    encode_address(created.organizer),
+                ],
+            )?;
+        }
+        PoapEvents::EventMinterAdded(added) => {
+            tx.execute(
+                "INSERT INTO event_minters (block_number, log_index, event_id, minter)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    block_number,
+                    log_index,
+                    encode_u256(added.eventId),
+                    encode_address(added.minter),
+                ],
+            )?;
+        }
+        PoapEvents::BadgeMinted(minted) => {
+            tx.execute(
+                "INSERT INTO badges (block_number, log_index, token_id, event_id, attendee)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    block_number,
+                    log_index,
+                    encode_u256(minted.tokenId),
+                    encode_u256(minted.eventId),
+                    encode_address(minted.attendee),
+                ],
+            )?;
+        }
+        PoapEvents::Transfer(transfer) => {
+            tx.execute(
+                "INSERT INTO transfers (block_number, log_index, token_id, from_address, to_address)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    block_number,
+                    log_index,
+                    encode_u256(transfer.tokenId),
+                    encode_address(transfer.from),
+                    encode_address(transfer.to),
+                ],
+            )?;
+        }
+        PoapEvents::OwnershipTransferred(_)
+        | PoapEvents::Upgraded(_)
+        | PoapEvents::TrustedForwarderSet(_)
+        | PoapEvents::EventMintHookSet(_)
+        | PoapEvents::MintHookFailed(_)
+        | PoapEvents::EventRoleAdded(_)
+        | PoapEvents::RoleMinterSet(_)
+        | PoapEvents::SessionAdded(_)
+        | PoapEvents::SessionCheckedIn(_)
+        | PoapEvents::AchievementCreated(_)
+        | PoapEvents::EventAllowlistRootSet(_) => return Ok(()),
+    }
+    insert_block(tx, block_number, block_hash)?;
+    Ok(())
+}
+
+/// Encodes a `U256` as fixed-width hex so that text order matches numeric
+/// order.
+fn encode_u256(value: U256) -> String {
+    format!("{value:#066x}")
+}
+
+fn encode_address(address: Address) -> String {
+    format!("{address:#x}")
+}
+
+/// Reads a text column holding a value written by [`encode_u256`],
+/// [`encode_address`] or a block hash.
+fn column<T>(row: &Row, index: usize) -> rusqlite::Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let text: String = row.get(index)?;
+    text.parse()
+        .map_err(|err| rusqlite::Error::FromSqlConversionFailure(index, Type::Text, Box::new(err)))
+}
+
+fn optional_column<T>(row: &Row, index: usize) -> rusqlite::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    match row.get_ref(index)? {
+        ValueRef::Null => Ok(None),
+        _ => column(row, index).map(Some),
+    }
+}
+
+fn event_from_row(row: &Row) -> rusqlite::Result<Event> {
+    Ok(Event {
+        id: column(row, 0)?,
+        name: row.get(1)?,
+        organizer: column(row, 2)?,
+        block_number: row.get(3)?,
+    })
+}
+
+fn badge_from_row(row: &Row) -> rusqlite::Result<Badge> {
+    Ok(Badge {
+        token_id: column(row, 0)?,
+        owner: column(row, 1)?,
+        event_id: optional_column(row, 2)?,
+        event_name: row.get(3)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{address, Log as PrimitiveLog},
+        sol_types::SolEvent,
+    };
+    use poap_client::abi::Poap;
+
+    use super::*;
+
+    const CONTRACT: Address = address!("0x00000000000000000000000000000000000000c0");
+    const ALICE: Address = address!("0x00000000000000000000000000000000000000a1");
+    const BOB: Address = address!("0x00000000000000000000000000000000000000b0");
+    const DAVE: Address = address!("0x00000000000000000000000000000000000000d0");
+
+    fn block_hash(number: u64) -> B256 {
+        B256::with_last_byte(number as u8)
+    }
+
+    fn log(block_number: u64, log_index: u64, event: impl SolEvent) -> Log {
+        Log {
+            inner: PrimitiveLog {
+                address: CONTRACT,
+                data: event.encode_log_data(),
+            },
+            block_number: Some(block_number),
+            block_hash: Some(block_hash(block_number)),
+            log_index: Some(log_index),
+            ..Default::default()
+        }
+    }
+
+    fn created(event_id: u64, organizer: Address) -> Poap::EventCreated {
+        Poap::EventCreated {
+            eventId: U256::from(event_id),
+            organizer,
+            name: format!("Event {event_id}"),
+        }
+    }
+
+    fn mint_logs(block_number: u64, event_id: u64, token_id: u64, to: Address) -> [Log; 2] {
+        [
+            log(
+                block_number,
+                0,
+                Poap::Transfer {
+                    from: Address::ZERO,
+                    to,
+                    tokenId: U256::from(token_id),
+                },
+            ),
+            log(
+                block_number,
+                1,
+                Poap::BadgeMinted {
+                    eventId: U256::from(event_id),
+                    tokenId: U256::from(token_id),
+                    attendee: to,
+                },
+            ),
+        ]
+    }
+
+    #[test]
+    fn indexes_events_and_minters() {
+        let mut store = Store::in_memory().unwrap();
+        let logs = [
+            log(1, 0, created(1, ALICE)),
+            log(
+                2,
+                0,
+                Poap::EventMinterAdded {
+                    eventId: U256::from(1),
+                    minter: BOB,
+                },
+            ),
+        ];
+        store.apply(&logs, (2, block_hash(2))).unwrap();
+
+        let event = store.event(U256::from(1)).unwrap().unwrap();
+        assert_eq!(event.name, "Event 1");
+        assert_eq!(event.organizer, ALICE);
+        assert_eq!(event.block_number, 1);
+        assert_eq!(store.events_by_organizer(ALICE).unwrap(), vec![event]);
+        assert_eq!(
+            store.event_minters(U256::from(1)).unwrap(),
+            vec![ALICE, BOB]
+        );
+        assert_eq!(store.latest_block().unwrap(), Some(2));
+    }
+
+    #[test]
+    fn tracks_holders_through_burns() {
+        let mut store = Store::in_memory().unwrap();
+        let mut logs = vec![log(1, 0, created(1, ALICE))];
+        logs.extend(mint_logs(2, 1, 1, DAVE));
+        logs.extend(mint_logs(3, 1, 2, BOB));
+        logs.push(log(
+            4,
+            0,
+            Poap::Transfer {
+                from: DAVE,
+                to: Address::ZERO,
+                tokenId: U256::from(1),
+            },
+        ));
+        store.apply(&logs, (4, block_hash(4))).unwrap();
+
+        assert!(store.holder_badges(DAVE).unwrap().is_empty());
+        assert_eq!(store.token(U256::from(1)).unwrap(), None);
+        assert_eq!(store.event_attendees(U256::from(1)).unwrap(), vec![BOB]);
+
+        let badges = store.holder_badges(BOB).unwrap();
+        assert_eq!(
+            badges,
+            vec![Badge {
+                token_id: U256::from(2),
+                owner: BOB,
+                event_id: Some(U256::from(1)),
+                event_name: Some(String::from("Event 1")),
+            }]
+        );
+    }
+
+    #[test]
+    fn rollback_discards_orphaned_blocks() {
+        let mut store = Store::in_memory().unwrap();
+        let mut logs = vec![log(1, 0, created(1, ALICE))];
+        logs.extend(mint_logs(2, 1, 1, DAVE));
+        logs.push(log(3, 0, created(2, BOB)));
+        store.apply(&logs, (3, block_hash(3))).unwrap();
+
+        store.rollback(2).unwrap();
+
+        assert_eq!(store.latest_block().unwrap(), Some(1));
+        assert_eq!(store.recent_blocks(10).unwrap(), vec![(1, block_hash(1))]);
+        assert!(store.event(U256::from(1)).unwrap().is_some());
+        assert_eq!(store.event(U256::from(2)).unwrap(), None);
+        assert!(store.holder_badges(DAVE).unwrap().is_empty());
+
+        // The replacement chain may reuse the same ids.
+        store
+            .apply(&[log(2, 0, created(2, DAVE))], (2, block_hash(2)))
+            .unwrap();
+        assert_eq!(store.event(U256::from(2)).unwrap().unwrap().organizer, DAVE);
+    }
+
+    #[test]
+    fn ignores_foreign_logs() {
+        let mut store = Store::in_memory().unwrap();
+        let foreign = Log {
+            inner: PrimitiveLog::new_unchecked(CONTRACT, vec![B256::ZERO], Default::default()),
+            block_number: Some(1),
+            block_hash: Some(block_hash(1)),
+            log_index: Some(0),
+            ..Default::default()
+        };
+        store.apply(&[foreign], (1, block_hash(1))).unwrap();
+
+        assert_eq!(store.latest_block().unwrap(), Some(1));
+        assert_eq!(store.event(U256::from(1)).unwrap(), None);
+    }
+}
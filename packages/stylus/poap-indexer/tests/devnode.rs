@@ -0,0 +1,62 @@
+//! Integration tests against a running nitro devnode.
+//!
+//! Deploy `Poap` (e.g. with `yarn deploy`) and run with:
+//!
+//! ```sh
+//! POAP_ADDRESS=0x... cargo test --features e2e
+//! ```
+//!
+//! `RPC_URL` and `PRIVATE_KEY` default to the devnode's endpoint and
+//! pre-funded account. The signer must own the deployed contract.
+#![cfg(feature = "e2e")]
+
+use alloy::{primitives::Address, providers::ProviderBuilder, signers::local::PrivateKeySigner};
+use eyre::Result;
+use poap_client::PoapClient;
+use poap_indexer::{Indexer, Store};
+
+const DEVNODE_RPC_URL: &str = "http://127.0.0.1:8547";
+const DEVNODE_PRIVATE_KEY: &str =
+    "0xb6b15c8cb491557369f3c7d2c287b053eb229daa9c22138887752191c9520659";
+
+#[tokio::test]
+async fn indexes_minted_badges() -> Result<()> {
+    let rpc_url = std::env::var("RPC_URL").unwrap_or_else(|_| DEVNODE_RPC_URL.to_owned());
+    let key = std::env::var("PRIVATE_KEY").unwrap_or_else(|_| DEVNODE_PRIVATE_KEY.to_owned());
+    let address: Address = std::env::var("POAP_ADDRESS")?.parse()?;
+    let signer: PrivateKeySigner = key.parse()?;
+    let provider = ProviderBuilder::new()
+        .wallet(signer)
+        .connect_http(rpc_url.parse()?);
+    let client = PoapClient::new(address, provider.clone());
+
+    let organizer = client.owner().await?;
+    let event_id = client.create_event("Indexer Test", organizer).await?;
+    let minter = Address::repeat_byte(0x1d);
+    client.add_event_minter(event_id, minter).await?;
+    let attendee = Address::repeat_byte(0xa7);
+    let token_id = client.mint_token(event_id, attendee).await?;
+
+    let mut indexer = Indexer::new(provider, address, Store::in_memory()?, 0);
+    indexer.sync_to_head().await?;
+    let store = indexer.store();
+
+    let event = store.event(event_id)?.expect("event should be indexed");
+    assert_eq!(event.name, "Indexer Test");
+    assert_eq!(event.organizer, organizer);
+    assert!(store.event_minters(event_id)?.contains(&minter));
+    assert_eq!(store.event_attendees(event_id)?, vec![attendee]);
+
+    let badge = store.token(token_id)?.expect("token should be indexed");
+    assert_eq!(badge.owner, attendee);
+    assert_eq!(badge.event_id, Some(event_id));
+    assert!(store
+        .holder_badges(attendee)?
+        .iter()
+        .any(|badge| badge.token_id == token_id));
+
+    // Nothing was reorged on the devnode, so a further pass rolls nothing back.
+    let report = indexer.sync_once().await?;
+    assert_eq!(report.rolled_back_from, None);
+    Ok(())
+}
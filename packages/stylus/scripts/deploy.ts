@@ -1,4 +1,5 @@
 import deployStylusContract from "./deploy_contract";
+import deployErc1967Proxy from "./deploy_proxy";
 import {
   getDeploymentConfig,
   getRpcUrlFromChain,
@@ -8,6 +9,8 @@ import { DeployOptions } from "./utils/type";
 import { config as dotenvConfig } from "dotenv";
 import * as path from "path";
 import * as fs from "fs";
+import { parseAbi } from "viem";
+import { arbitrumNitro } from "../../nextjs/utils/scaffold-stylus/supportedChains";
 
 const envPath = path.resolve(__dirname, "../.env");
 if (fs.existsSync(envPath)) {
@@ -44,13 +47,45 @@ export default async function deployScript(deployOptions: DeployOptions) {
   //   ...deployOptions,
   // });
 
-  await deployStylusContract({
+  // Poap runs behind an ERC-1967 proxy, which initializes it in the same
+  // transaction it is deployed in
+  const implementation = await deployStylusContract({
     contract: "erc721-example",
-    name: "erc721-example",
-    constructorArgs: ["POAP", "POAP", "https://ipfs.io/ipfs/", "0x5e47df5e239fc0585b878692d2e8f6d53455f214"],
+    name: "erc721-example-implementation",
     ...deployOptions,
   });
 
+  if (implementation) {
+    await deployErc1967Proxy({
+      contract: "erc721-example",
+      name: "erc721-example",
+      implementation: implementation.address,
+      initializeAbi: parseAbi([
+        "function initialize(string name, string symbol, string base_uri, address owner)",
+      ]),
+      constructorArgs: ["POAP", "POAP", "https://ipfs.io/ipfs/", "0x5e47df5e239fc0585b878692d2e8f6d53455f214"],
+      ...deployOptions,
+    });
+
+    // Partner collections are clones of the Poap implementation
+    await deployStylusContract({
+      contract: "poap-factory",
+      name: "poap-factory",
+      constructorArgs: [implementation.address],
+      ...deployOptions,
+    });
+  }
+
+  // Relays signed calls for local gasless testing; enable it on Poap with
+  // setTrustedForwarder. Public networks use a production relayer instead.
+  if (config.chain.id === arbitrumNitro.id) {
+    await deployStylusContract({
+      contract: "poap-forwarder",
+      name: "poap-forwarder",
+      ...deployOptions,
+    });
+  }
+
   // Print the deployed addresses
   console.log("\n\n");
   printDeployedAddresses(config.deploymentDir, config.chain?.id.toString());
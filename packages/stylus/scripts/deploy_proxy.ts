@@ -0,0 +1,155 @@
+import {
+  getDeploymentConfig,
+  ensureDeploymentDirectory,
+  saveDeployment,
+  getBlockExplorerUrlFromChain,
+} from "./utils/";
+import { exportStylusAbi } from "./export_abi";
+import { DeploymentData, DeployOptions } from "./utils/type";
+import {
+  Abi,
+  Address,
+  Hex,
+  createPublicClient,
+  createWalletClient,
+  encodeFunctionData,
+  http,
+  parseAbi,
+} from "viem";
+import { privateKeyToAccount } from "viem/accounts";
+import * as path from "path";
+import * as fs from "fs";
+
+/**
+ * Hand-assembled ERC-1967 proxy. The constructor takes
+ * `(address implementation, bytes data)`: it reverts unless `implementation`
+ * has code, stores it in the implementation slot, emits
+ * `Upgraded(implementation)` and delegate-calls `data` unless it is empty,
+ * bubbling up a revert. Every later call is delegated to the implementation
+ * in the slot, which upgrades itself through `upgradeToAndCall` (UUPS).
+ *
+ * The bytecode lives in `erc1967_proxy.hex`, which the erc721-example e2e
+ * tests and `poap-client` load too, so all of them deploy the same proxy.
+ * Listing, with `SLOT` the ERC-1967 implementation slot and `UPGRADED` the
+ * `Upgraded(address)` topic:
+ *
+ * ```
+ * 60bf80380380915f3950          codecopy(0, 0xbf, codesize - 0xbf): constructor arguments to memory
+ * 5f51803b6014575f5ffd5b        implementation = mload(0), revert if it has no code
+ * 807f{SLOT}55                  sstore(SLOT, implementation)
+ * 807f{UPGRADED}5f5fa2          log2(0, 0, UPGRADED, implementation)
+ * 60205180518015607b57          data = mload(0x20), skip the call if it is empty
+ * 5f5f8284602001865af4607b57    delegatecall(gas, implementation, data + 0x20, len(data), 0, 0)
+ * 3d5f5f3e3d5ffd                bubble up the revert
+ * 5b603a8060855f395ff3          return the 0x3a bytes of runtime code below
+ * 365f5f37                      runtime: calldatacopy(0, 0, calldatasize)
+ * 5f5f365f7f{SLOT}545af4        delegatecall(gas, sload(SLOT), 0, calldatasize, 0, 0)
+ * 3d5f5f3e6036573d5ffd5b3d5ff3  returndatacopy(0, 0, returndatasize), then return or revert it
+ * ```
+ */
+export const ERC1967_PROXY_BYTECODE = `0x${fs
+  .readFileSync(path.resolve(__dirname, "erc1967_proxy.hex"), "utf8")
+  .trim()}` as Hex;
+
+const ERC1967_PROXY_ABI = parseAbi([
+  "constructor(address implementation, bytes data)",
+]);
+
+interface ProxyDeployOptions extends DeployOptions {
+  implementation: Address;
+  initializeAbi: Abi;
+}
+
+/**
+ * Deploy an ERC-1967 proxy in front of a deployed Stylus contract
+ * @param deployOptions - The deploy options. `contract` is the folder of the
+ * implementation, whose ABI is exported under `name` at the proxy address,
+ * and `constructorArgs` are passed to its `initialize` function
+ * @returns The proxy address and transaction hash, unless only estimating gas
+ */
+export default async function deployErc1967Proxy(
+  deployOptions: ProxyDeployOptions,
+): Promise<DeploymentData | undefined> {
+  console.log(`\n🚀 Deploying ERC-1967 proxy for: ${deployOptions.contract}`);
+
+  const config = getDeploymentConfig(deployOptions);
+  ensureDeploymentDirectory(config.deploymentDir);
+
+  console.log(`📄 Contract name: ${config.contractName}`);
+  console.log(`📋 Implementation: ${deployOptions.implementation}`);
+
+  if (deployOptions.estimateGas) {
+    return;
+  }
+
+  try {
+    const publicClient = createPublicClient({
+      chain: config.chain,
+      transport: http(),
+    });
+
+    const walletClient = createWalletClient({
+      chain: config.chain,
+      transport: http(),
+    });
+
+    const account = privateKeyToAccount(config.privateKey as `0x${string}`);
+
+    // Initialize in the deployment transaction, so it cannot be front-run
+    const initData = encodeFunctionData({
+      abi: deployOptions.initializeAbi,
+      functionName: "initialize",
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      args: deployOptions.constructorArgs as any[],
+    });
+
+    const txHash = await walletClient.deployContract({
+      account,
+      abi: ERC1967_PROXY_ABI,
+      bytecode: ERC1967_PROXY_BYTECODE,
+      args: [deployOptions.implementation, initData],
+    });
+    const receipt = await publicClient.waitForTransactionReceipt({
+      hash: txHash,
+    });
+    if (receipt.status !== "success" || !receipt.contractAddress) {
+      throw new Error(`Proxy deployment reverted: ${txHash}`);
+    }
+
+    const deploymentInfo: DeploymentData = {
+      address: receipt.contractAddress,
+      txHash,
+    };
+
+    const blockExplorerUrl = getBlockExplorerUrlFromChain(config.chain);
+    if (blockExplorerUrl) {
+      console.log(
+        `📋 Proxy deployed: ${blockExplorerUrl}/address/${deploymentInfo.address}`,
+      );
+      console.log(`Transaction hash: ${blockExplorerUrl}/tx/${txHash}`);
+    } else {
+      console.log(`📋 Proxy deployed at address: ${deploymentInfo.address}`);
+      console.log("Transaction hash: ", txHash);
+    }
+
+    // Save the proxy with the implementation's ABI, so the frontend talks to
+    // the implementation through it
+    saveDeployment(config, deploymentInfo);
+    await exportStylusAbi(
+      config.contractFolder,
+      config.contractName,
+      false,
+      config.chain.id.toString(),
+    );
+
+    return deploymentInfo;
+  } catch (error) {
+    console.error(`❌ Proxy deployment failed in: ${deployOptions.contract}`);
+    if (error instanceof Error) {
+      console.error(error.message);
+    } else {
+      console.error(error);
+    }
+    process.exit(1);
+  }
+}
@@ -37,7 +37,8 @@ export async function buildDeployCommand(
   if (
     deployOptions.constructorArgs &&
     deployOptions.constructorArgs.length > 0 &&
-    !deployOptions.isOrbit
+    !deployOptions.isOrbit &&
+    isContractHasConstructor(config.contractFolder)
   ) {
     baseCommand += ` --constructor-args ${deployOptions.constructorArgs.map((arg) => `"${arg}"`).join(" ")} `;
   }